@@ -0,0 +1,172 @@
+//! Derive macros for `game_solver`. Use these through the `derive` feature of `game_solver`,
+//! which re-exports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Fields, LitStr, Member,
+};
+
+/// Derives `Game` for a two player partizan game, from its `GameRules`.
+///
+/// The struct needs a `#[game(max_moves = ..., state_type = "...")]` attribute,
+/// where `max_moves` is a constant expression and `state_type` is either `"Normal"` or `"Misere"`,
+/// and one `usize` field marked `#[game(move_count)]`, which counts the moves made.
+///
+/// The generated implementation:
+/// - counts moves in the marked field, after every successful `GameRules::apply_move`,
+/// - has the players (`PartizanPlayer`) take turns, starting with `Left`,
+/// - decides the state with the chosen `StateType`,
+/// - and takes its moves from `GameRules`, which is left to be implemented.
+#[proc_macro_derive(Game, attributes(game))]
+pub fn derive_game(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn derive(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut max_moves: Option<Expr> = None;
+    let mut state_type: Option<LitStr> = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("game"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("max_moves") {
+                max_moves = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("state_type") {
+                state_type = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `max_moves` or `state_type`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let ident = &input.ident;
+    let max_moves = max_moves.ok_or_else(|| {
+        Error::new(
+            ident.span(),
+            "missing `#[game(max_moves = ...)]` on the struct",
+        )
+    })?;
+    let state_type = state_type.ok_or_else(|| {
+        Error::new(
+            ident.span(),
+            "missing `#[game(state_type = \"...\")]` on the struct",
+        )
+    })?;
+    let state_type = match state_type.value().as_str() {
+        "Normal" => quote!(::game_solver::game::StateType::Normal),
+        "Misere" => quote!(::game_solver::game::StateType::Misere),
+        _ => {
+            return Err(Error::new(
+                state_type.span(),
+                "expected `\"Normal\"` or `\"Misere\"`",
+            ))
+        }
+    };
+
+    let move_count = move_count_field(&input)?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let rules = quote!(<Self as ::game_solver::game::GameRules>);
+
+    Ok(quote! {
+        impl #impl_generics ::game_solver::game::Game for #ident #ty_generics #where_clause {
+            type Move = #rules::Move;
+            type Iter<'a> = #rules::Iter<'a> where Self: 'a;
+            type MoveError = #rules::MoveError;
+            type Player = ::game_solver::player::PartizanPlayer;
+
+            fn move_count(&self) -> usize {
+                self.#move_count
+            }
+
+            fn max_moves(&self) -> Option<usize> {
+                Some(#max_moves)
+            }
+
+            fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+                ::game_solver::game::GameRules::apply_move(self, m)?;
+                self.#move_count += 1;
+                Ok(())
+            }
+
+            fn possible_moves(&self) -> Self::Iter<'_> {
+                ::game_solver::game::GameRules::moves(self)
+            }
+
+            fn state(&self) -> ::game_solver::game::GameState<Self::Player> {
+                #state_type.state(self)
+            }
+
+            fn player(&self) -> Self::Player {
+                if self.#move_count % 2 == 0 {
+                    ::game_solver::player::PartizanPlayer::Left
+                } else {
+                    ::game_solver::player::PartizanPlayer::Right
+                }
+            }
+        }
+    })
+}
+
+/// The field marked with `#[game(move_count)]`.
+fn move_count_field(input: &DeriveInput) -> syn::Result<Member> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`Game` can only be derived for structs",
+        ));
+    };
+
+    let fields = match &data.fields {
+        Fields::Named(fields) => &fields.named,
+        Fields::Unnamed(fields) => &fields.unnamed,
+        Fields::Unit => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`Game` needs a `#[game(move_count)]` field to count moves in",
+            ))
+        }
+    };
+
+    let mut marked = None;
+    for (index, field) in fields.iter().enumerate() {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("game"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("move_count") {
+                    return Err(meta.error("expected `move_count`"));
+                }
+                if marked.is_some() {
+                    return Err(meta.error("only one field can be the `move_count`"));
+                }
+
+                marked = Some(match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::from(index),
+                });
+                Ok(())
+            })?;
+        }
+    }
+
+    marked.ok_or_else(|| {
+        Error::new(
+            fields.span(),
+            "`Game` needs a `#[game(move_count)]` field to count moves in",
+        )
+    })
+}
@@ -0,0 +1,140 @@
+//! A small harness for comparing solver configurations on the same game.
+//!
+//! This is meant for development, not for solving games in production:
+//! every run solves the game from scratch, and results are kept around
+//! to be printed as a single comparison table.
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    hash::Hash,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    config::SolverConfig, game::Game, heuristic::MoveOrderer, player::TwoPlayer, solve_with_config,
+    stats::Stats, transposition::TranspositionTable, GameSolveError,
+};
+
+/// The measurements of a single benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchmarkResult {
+    pub name: String,
+    pub score: isize,
+    pub time: Duration,
+    pub states_explored: u64,
+    pub cache_hits: u64,
+}
+
+/// Solves the same game under different configurations (e.g. move orderers), recording
+/// the wall time and amount of nodes explored by each run.
+pub struct GameBenchmark<T: Game> {
+    game: T,
+    results: Vec<BenchmarkResult>,
+}
+
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash> GameBenchmark<T> {
+    pub fn new(game: T) -> Self {
+        Self {
+            game,
+            results: Vec::new(),
+        }
+    }
+
+    /// Solves the game with `config`, using a fresh [`HashMap`] as the transposition table.
+    pub fn run<O: MoveOrderer<T>>(
+        &mut self,
+        name: &str,
+        config: SolverConfig<O>,
+    ) -> Result<BenchmarkResult, GameSolveError<T>> {
+        self.run_with_table(name, config, &mut HashMap::new())
+    }
+
+    /// Solves the game with `config`, using the provided transposition table.
+    /// Useful for comparing transposition table implementations.
+    pub fn run_with_table<O: MoveOrderer<T>>(
+        &mut self,
+        name: &str,
+        config: SolverConfig<O>,
+        transposition_table: &mut dyn TranspositionTable<T>,
+    ) -> Result<BenchmarkResult, GameSolveError<T>> {
+        let stats = Stats::new(self.game.player(), self.game.move_count());
+
+        let start = Instant::now();
+        let score = solve_with_config(&self.game, transposition_table, &config, Some(&stats))?;
+        let time = start.elapsed();
+
+        let result = BenchmarkResult {
+            name: name.to_string(),
+            score,
+            time,
+            states_explored: stats.states_explored.load(Ordering::Relaxed),
+            cache_hits: stats.cache_hits.load(Ordering::Relaxed),
+        };
+
+        self.results.push(result.clone());
+
+        Ok(result)
+    }
+
+    /// Every result recorded so far, in the order they were run.
+    pub fn results(&self) -> &[BenchmarkResult] {
+        &self.results
+    }
+
+    /// Formats every recorded result as a comparison table.
+    pub fn report(&self) -> String {
+        let name_width = self
+            .results
+            .iter()
+            .map(|result| result.name.len())
+            .chain(std::iter::once("name".len()))
+            .max()
+            .unwrap();
+
+        let mut report = format!(
+            "{:<name_width$}  {:>8}  {:>12}  {:>14}  {:>12}\n",
+            "name", "score", "time", "states", "cache hits"
+        );
+
+        for result in &self.results {
+            writeln!(
+                report,
+                "{:<name_width$}  {:>8}  {:>12}  {:>14}  {:>12}",
+                result.name,
+                result.score,
+                format!("{:.2?}", result.time),
+                result.states_explored,
+                result.cache_hits
+            )
+            .unwrap();
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::Nim;
+
+    #[test]
+    fn benchmark_configs_agree() {
+        let mut benchmark = GameBenchmark::new(Nim::new(vec![3, 4, 5]));
+
+        let hard_fail = benchmark.run("hard fail", SolverConfig::new()).unwrap();
+        let soft_fail = benchmark
+            .run("soft fail", SolverConfig::new().with_soft_fail(true))
+            .unwrap();
+
+        assert_eq!(hard_fail.score, soft_fail.score);
+        assert!(hard_fail.states_explored > 0);
+        assert_eq!(benchmark.results().len(), 2);
+
+        let report = benchmark.report();
+        assert!(report.contains("hard fail"));
+        assert!(report.contains("soft fail"));
+    }
+}
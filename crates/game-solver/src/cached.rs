@@ -0,0 +1,235 @@
+//! A game wrapper that remembers its moves and state between queries.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    cell::OnceCell,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    iter::Cloned,
+    slice,
+};
+
+use crate::game::{ExactGame, Game, GameState};
+
+/// Wraps a game whose [`Game::possible_moves`] and [`Game::state`] are expensive,
+/// computing each only once per position.
+///
+/// The solver asks a position for its moves and its state several times
+/// (e.g. in [`Game::find_immediately_resolvable_game`], then again to search),
+/// so games that naturally evaluate these from scratch can wrap themselves in this
+/// instead of caching by hand. Making a move clears both caches.
+///
+/// Every other method is delegated to the wrapped game, except for [`Game::canonical_moves`]
+/// and [`Game::noisy_moves`], which return every cached move, as only
+/// [`Game::possible_moves`] is cached. The caches are ignored when comparing, hashing, or formatting positions.
+#[derive(Clone)]
+pub struct CachedGame<T: Game>
+where
+    T::Player: Clone,
+{
+    game: T,
+    moves: OnceCell<Vec<T::Move>>,
+    state: OnceCell<GameState<T::Player>>,
+}
+
+impl<T: Game> CachedGame<T>
+where
+    T::Player: Clone,
+{
+    pub fn new(game: T) -> Self {
+        Self {
+            game,
+            moves: OnceCell::new(),
+            state: OnceCell::new(),
+        }
+    }
+
+    /// The wrapped game.
+    pub fn inner(&self) -> &T {
+        &self.game
+    }
+
+    pub fn into_inner(self) -> T {
+        self.game
+    }
+}
+
+// the moves borrow from the cache, and `ExactGame` bounds them for every lifetime, so this needs `T: 'static`.
+impl<T: Game + 'static> ExactGame for CachedGame<T> where T::Player: Clone {}
+
+impl<T: Game + Debug> Debug for CachedGame<T>
+where
+    T::Player: Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.game.fmt(f)
+    }
+}
+
+impl<T: Game + PartialEq> PartialEq for CachedGame<T>
+where
+    T::Player: Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.game == other.game
+    }
+}
+
+impl<T: Game + Eq> Eq for CachedGame<T> where T::Player: Clone {}
+
+impl<T: Game + Hash> Hash for CachedGame<T>
+where
+    T::Player: Clone,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.game.hash(state);
+    }
+}
+
+impl<T: Game> Game for CachedGame<T>
+where
+    T::Player: Clone,
+{
+    type Move = T::Move;
+    type Iter<'a> = Cloned<slice::Iter<'a, T::Move>> where T: 'a;
+    type MoveError = T::MoveError;
+    type Player = T::Player;
+
+    fn move_count(&self) -> usize {
+        self.game.move_count()
+    }
+
+    fn max_moves(&self) -> Option<usize> {
+        self.game.max_moves()
+    }
+
+    fn min_score(&self) -> Option<isize> {
+        self.game.min_score()
+    }
+
+    fn max_score(&self) -> Option<isize> {
+        self.game.max_score()
+    }
+
+    fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+        self.moves = OnceCell::new();
+        self.state = OnceCell::new();
+        self.game.make_move(m)
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        self.moves
+            .get_or_init(|| self.game.possible_moves().collect())
+            .iter()
+            .cloned()
+    }
+
+    fn find_immediately_resolvable_game_among(
+        &self,
+        moves: &[Self::Move],
+    ) -> Result<Option<Self>, Self::MoveError> {
+        Ok(self
+            .game
+            .find_immediately_resolvable_game_among(moves)?
+            .map(Self::new))
+    }
+
+    fn is_quiet(&self) -> bool {
+        self.game.is_quiet()
+    }
+
+    fn is_winning_move(&self, m: &Self::Move) -> bool {
+        self.game.is_winning_move(m)
+    }
+
+    fn score_after_winning_move(&self, m: &Self::Move) -> Option<isize> {
+        self.game.score_after_winning_move(m)
+    }
+
+    fn move_to_index(&self, m: &Self::Move) -> Option<usize> {
+        self.game.move_to_index(m)
+    }
+
+    fn move_from_index(&self, index: usize) -> Option<Self::Move> {
+        self.game.move_from_index(index)
+    }
+
+    fn move_to_index_count(&self) -> Option<usize> {
+        self.game.move_to_index_count()
+    }
+
+    fn canonical_form(&self) -> Self {
+        Self::new(self.game.canonical_form())
+    }
+
+    fn display_move(&self, m: &Self::Move) -> String
+    where
+        Self::Move: Debug,
+    {
+        self.game.display_move(m)
+    }
+
+    fn moves_equal(&self, a: &Self::Move, b: &Self::Move) -> bool
+    where
+        Self::Move: PartialEq,
+    {
+        self.game.moves_equal(a, b)
+    }
+
+    fn is_draw(&self) -> bool {
+        self.game.is_draw()
+    }
+
+    fn is_repeated_position(&self) -> bool {
+        self.game.is_repeated_position()
+    }
+
+    fn repetition_count(&self) -> usize {
+        self.game.repetition_count()
+    }
+
+    fn is_repetition_draw(&self) -> bool {
+        self.game.is_repetition_draw()
+    }
+
+    fn evaluate(&self, maximizer: Self::Player) -> Option<isize> {
+        self.game.evaluate(maximizer)
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        self.state.get_or_init(|| self.game.state()).clone()
+    }
+
+    fn player(&self) -> Self::Player {
+        self.game.player()
+    }
+}
+
+// (the tests use the Nim test game, which needs `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{game::move_count_exact, solve, tests::Nim};
+
+    #[test]
+    fn cached_game_matches_game() {
+        for heaps in [vec![3], vec![2, 2], vec![1, 2, 3], vec![3, 4]] {
+            let nim = Nim::new(heaps);
+            let cached = CachedGame::new(nim.clone());
+
+            assert!(cached.possible_moves().eq(nim.possible_moves()));
+            assert_eq!(
+                solve(&cached, &mut HashMap::new(), None).unwrap(),
+                solve(&nim, &mut HashMap::new(), None).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn exact_move_counts() {
+        let cached = CachedGame::new(Nim::new(vec![1, 2, 3]));
+        assert_eq!(move_count_exact(&cached), 6);
+    }
+}
@@ -0,0 +1,88 @@
+//! Configuration for the solver's search.
+
+use crate::heuristic::NoMoveOrderer;
+
+/// Options that change how [`crate::solve_with_config`] searches a game.
+///
+/// Every option defaults to the behavior of [`crate::solve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverConfig<O = NoMoveOrderer> {
+    /// Use the soft-fail negamax search (see [`crate::negamax_soft_fail`])
+    /// instead of the hard-fail one.
+    pub soft_fail: bool,
+    /// Reorders moves before they're searched (see [`crate::heuristic::MoveOrderer`]).
+    pub move_orderer: Option<O>,
+    /// The score of a draw for the player to move in the solved position
+    /// (and the negation of it for their opponent), e.g. to prefer draws over risky lines.
+    ///
+    /// This should be smaller (in magnitude) than the score of any win, so that a draw
+    /// is never mistaken for one. Since it's relative to the solved position, scores that
+    /// depend on a nonzero draw value aren't stored in the transposition table,
+    /// which makes games with many draws slower to solve.
+    pub draw_value: isize,
+    /// The width of the first window to search, centered on a tie,
+    /// or `None` to narrow the window by binary search instead.
+    ///
+    /// Every time the score falls outside of the window, the window is moved past it,
+    /// and its width doubled. If scores are usually close to 0, this takes fewer searches
+    /// than the binary search, which starts out at the full range of scores.
+    pub aspiration_window_initial: Option<isize>,
+    /// Look for a forced win (see [`crate::find_forced_win`]) of up to this many moves
+    /// before searching, returning its score right away if there is one.
+    ///
+    /// Forced variations are much cheaper to search than the whole game, so this speeds up
+    /// tactical positions, at the cost of searching the forcing lines again in positions
+    /// without a short win.
+    pub forced_win_depth: Option<usize>,
+}
+
+impl SolverConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            soft_fail: false,
+            move_orderer: None,
+            draw_value: 0,
+            aspiration_window_initial: None,
+            forced_win_depth: None,
+        }
+    }
+}
+
+impl<O> SolverConfig<O> {
+    pub fn with_soft_fail(mut self, soft_fail: bool) -> Self {
+        self.soft_fail = soft_fail;
+        self
+    }
+
+    pub fn with_move_orderer<P>(self, move_orderer: P) -> SolverConfig<P> {
+        SolverConfig {
+            soft_fail: self.soft_fail,
+            move_orderer: Some(move_orderer),
+            draw_value: self.draw_value,
+            aspiration_window_initial: self.aspiration_window_initial,
+            forced_win_depth: self.forced_win_depth,
+        }
+    }
+
+    pub fn with_draw_value(mut self, draw_value: isize) -> Self {
+        self.draw_value = draw_value;
+        self
+    }
+
+    pub fn with_aspiration_window(mut self, initial_window: isize) -> Self {
+        self.aspiration_window_initial = Some(initial_window);
+        self
+    }
+
+    pub fn with_forced_win_depth(mut self, max_depth: usize) -> Self {
+        self.forced_win_depth = Some(max_depth);
+        self
+    }
+}
@@ -0,0 +1,170 @@
+//! Cooperative games, where every player works towards the same goal.
+//!
+//! These are usually puzzles (e.g. sokoban, or cooperative card games):
+//! every move is made in the common interest of all players, so rather than one player's gain
+//! being another's loss, the solver looks for the best outcome for everyone.
+
+use core::{hash::Hash, sync::atomic::Ordering};
+
+use crate::{
+    bounded_max_score,
+    game::{Game, GameState},
+    stats::Stats,
+    transposition::{Score, TranspositionTable},
+    GameSolveError,
+};
+
+/// Marks a game as cooperative: every player wants the game to be won,
+/// no matter which player it's won by.
+pub trait CooperativeGame: Game {}
+
+/// Like negamax, but every position maximizes the score, instead of negating its children's.
+fn cooperative_search<T: CooperativeGame + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    if let Some(stats) = stats {
+        stats.states_explored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    if game.is_repeated_position() || game.is_draw() || game.is_repetition_draw() {
+        if let Some(stats) = stats {
+            stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok(0);
+    }
+
+    match game.state() {
+        GameState::Playable => (),
+        GameState::Tie => {
+            if let Some(stats) = stats {
+                stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
+            }
+            return Ok(0);
+        }
+        GameState::Win(_) => {
+            if let Some(stats) = stats {
+                stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
+            }
+            return bounded_max_score(game);
+        }
+    }
+
+    if let Some(Score::Exact(score)) = transposition_table.get(game) {
+        if let Some(stats) = stats {
+            stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok(score);
+    }
+
+    // winning on the next move is the best any move can do.
+    let best_possible = bounded_max_score(game)? - 1;
+    let mut best = 0;
+
+    for m in game.possible_moves() {
+        let mut board = game.clone();
+        board
+            .make_move(&m)
+            .map_err(|err| GameSolveError::MoveError(err))?;
+
+        best = best.max(cooperative_search(&board, transposition_table, stats)?);
+
+        if best >= best_possible {
+            if let Some(stats) = stats {
+                stats.pruning_cutoffs.fetch_add(1, Ordering::Relaxed);
+            }
+            break;
+        }
+    }
+
+    // every position is searched with the same (lack of a) window, so every score is exact.
+    transposition_table.insert(game.clone(), Score::Exact(best));
+
+    Ok(best)
+}
+
+/// Solves a cooperative game, returning the best score all players can achieve together.
+///
+/// Like [`crate::solve`], a win has a positive score, which is higher the sooner the game is won.
+/// If no sequence of moves wins the game, the score is 0.
+pub fn cooperative_solve<T: CooperativeGame + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    cooperative_search(game, transposition_table, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::player::PartizanPlayer;
+
+    /// Players take turns adding 1 or 2 to a running total,
+    /// and win together if they land exactly on the target.
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct CountUp {
+        total: usize,
+        target: usize,
+        move_count: usize,
+    }
+
+    impl Game for CountUp {
+        type Move = usize;
+        type Iter<'a> = core::array::IntoIter<usize, 2>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.target + 1)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.total += m;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            // adding 1 comes first, so the fastest solution isn't the first one found
+            [1, 2].into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            match self.total.cmp(&self.target) {
+                core::cmp::Ordering::Less => GameState::Playable,
+                core::cmp::Ordering::Equal => GameState::Win(self.player()),
+                core::cmp::Ordering::Greater => GameState::Tie,
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    impl CooperativeGame for CountUp {}
+
+    #[test]
+    fn finds_fastest_cooperative_win() {
+        let game = CountUp {
+            total: 0,
+            target: 5,
+            move_count: 0,
+        };
+
+        // 2 + 2 + 1 is the fastest way to reach 5, in 3 moves.
+        assert_eq!(
+            cooperative_solve(&game, &mut BTreeMap::new(), None).unwrap(),
+            (5 + 1) - 3 + 1
+        );
+    }
+}
@@ -5,7 +5,8 @@ use crate::{
     game::{Game, Normal, NormalImpartial},
     player::ImpartialPlayer,
 };
-use std::{fmt::Debug, iter::Map};
+use alloc::boxed::Box;
+use core::{fmt::Debug, iter::Map};
 
 /// Represents the disjoint sum of
 /// two impartial normal combinatorial games.
@@ -65,8 +66,7 @@ impl<L: Game + Debug + NormalImpartial + 'static, R: Game + Debug + NormalImpart
     fn max_moves(&self) -> Option<usize> {
         self.left
             .max_moves()
-            .map(|l| self.right.max_moves().map(|r| l + r))
-            .flatten()
+            .and_then(|l| self.right.max_moves().map(|r| l + r))
     }
 
     fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
@@ -102,7 +102,7 @@ impl<L: Game + Debug + NormalImpartial + 'static, R: Game + Debug + NormalImpart
     }
 
     fn state(&self) -> crate::game::GameState<Self::Player> {
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 
     fn player(&self) -> Self::Player {
@@ -1,6 +1,14 @@
 //! Game trait and related types.
 
-use std::{cmp::Ordering, error::Error};
+use alloc::{borrow::Cow, format, string::String, sync::Arc, vec::Vec};
+use core::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
+
+use thiserror::Error;
 
 use crate::player::Player;
 
@@ -16,6 +24,51 @@ pub enum GameState<P: Player> {
     Win(P),
 }
 
+impl<P: Player> GameState<P> {
+    /// Returns true if the game is over, whether by a win or a tie.
+    pub const fn is_terminal(&self) -> bool {
+        !matches!(self, Self::Playable)
+    }
+
+    /// Returns the player who won, if any.
+    pub const fn winner(&self) -> Option<&P> {
+        match self {
+            Self::Win(player) => Some(player),
+            Self::Playable | Self::Tie => None,
+        }
+    }
+}
+
+/// Yields the winner, if any.
+impl<P: Player> IntoIterator for GameState<P> {
+    type Item = P;
+    type IntoIter = core::option::IntoIter<P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Option::from(self).into_iter()
+    }
+}
+
+/// The winner, if any.
+impl<P: Player> From<GameState<P>> for Option<P> {
+    fn from(state: GameState<P>) -> Self {
+        match state {
+            GameState::Win(player) => Some(player),
+            GameState::Playable | GameState::Tie => None,
+        }
+    }
+}
+
+impl<P: Player + Display> Display for GameState<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Playable => write!(f, "Game in progress"),
+            Self::Tie => write!(f, "Tie"),
+            Self::Win(player) => write!(f, "{player} wins"),
+        }
+    }
+}
+
 /// Marks a game as being 'normal' (a game has the 'normal play' convention).
 ///
 /// Rather, this means that the game is won by whoever plays last.
@@ -65,6 +118,126 @@ pub trait Misere: Game {
     }
 }
 
+/// The rules of a game that derives [`Game`] (with the `derive` feature):
+/// what moves there are, and how they change the position.
+///
+/// ```ignore
+/// use game_solver::game::{Game, GameRules};
+///
+/// #[derive(Game, Clone)]
+/// #[game(max_moves = 9, state_type = "Normal")]
+/// struct MyGame {
+///     #[game(move_count)]
+///     moves: usize,
+///     // ...
+/// }
+///
+/// impl GameRules for MyGame {
+///     // ...
+/// }
+/// ```
+///
+/// The derived [`Game`] counts moves itself, so [`GameRules::apply_move`] shouldn't.
+/// (Methods are named differently from [`Game`]'s, so calls aren't ambiguous with both in scope.)
+pub trait GameRules {
+    /// See [`Game::Move`].
+    type Move: Clone;
+
+    /// See [`Game::Iter`].
+    type Iter<'a>: Iterator<Item = Self::Move> + 'a
+    where
+        Self: 'a;
+
+    /// See [`Game::MoveError`].
+    type MoveError: Error;
+
+    /// See [`Game::possible_moves`].
+    fn moves(&self) -> Self::Iter<'_>;
+
+    /// See [`Game::make_move`].
+    fn apply_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError>;
+}
+
+#[cfg(feature = "derive")]
+pub use game_solver_derive::Game;
+
+/// Detects wins with two cheap predicates, for games where checking who won is cheaper
+/// than computing the whole [`GameState`] (e.g. checking a counter).
+///
+/// [`Game::state`] can then be implemented as `<Self as WinnerDetector>::state(self)`.
+pub trait WinnerDetector: Game {
+    /// Returns true if the player to move has already won.
+    fn current_player_wins(&self) -> bool;
+
+    /// Returns true if the player who moved last won.
+    fn previous_player_wins(&self) -> bool;
+
+    /// The state built from the two predicates: a game that nobody has won
+    /// is tied once it can't be continued.
+    fn state(&self) -> GameState<Self::Player> {
+        if self.current_player_wins() {
+            GameState::Win(self.player())
+        } else if self.previous_player_wins() {
+            GameState::Win(self.player().previous())
+        } else if self.possible_moves().next().is_none() {
+            GameState::Tie
+        } else {
+            GameState::Playable
+        }
+    }
+}
+
+/// How a game decides who won, for games that would rather pick a play convention
+/// than implement [`Game::state`] themselves.
+///
+/// [`Game::state`] can then be implemented as `StateType::Normal.state(self)`,
+/// or with a [`StateType::Custom`] for games with their own terminal conditions
+/// (e.g. Dots and Boxes, where the game ends once every box is claimed).
+#[non_exhaustive]
+pub enum StateType<T: Game> {
+    /// Whoever plays last wins (see [`Normal`]).
+    Normal,
+    /// Whoever plays last loses (see [`Misere`]).
+    Misere,
+    /// The state is decided by the closure.
+    #[allow(clippy::type_complexity)]
+    Custom(Arc<dyn Fn(&T) -> GameState<T::Player> + Send + Sync>),
+}
+
+impl<T: Game> StateType<T> {
+    /// The state of `game` under this convention.
+    pub fn state(&self, game: &T) -> GameState<T::Player> {
+        match self {
+            Self::Normal if game.possible_moves().next().is_none() => {
+                GameState::Win(game.player().previous())
+            }
+            Self::Misere if game.possible_moves().next().is_none() => GameState::Win(game.player()),
+            Self::Normal | Self::Misere => GameState::Playable,
+            Self::Custom(state) => state(game),
+        }
+    }
+}
+
+impl<T: Game> Clone for StateType<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Normal => Self::Normal,
+            Self::Misere => Self::Misere,
+            Self::Custom(state) => Self::Custom(Arc::clone(state)),
+        }
+    }
+}
+
+impl<T: Game> fmt::Debug for StateType<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(f, "Normal"),
+            Self::Misere => write!(f, "Misere"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
 /// Represents a combinatorial game.
 ///
 /// A game has three distinct variants per game:
@@ -77,10 +250,18 @@ pub trait Game: Clone {
     type Move: Clone;
 
     /// The iterator type for possible moves.
+    ///
+    /// Games that always know how many moves they have can use an [`ExactSizeIterator`] here
+    /// (e.g. `std::vec::IntoIter`) and opt into [`ExactGame`].
     type Iter<'a>: Iterator<Item = Self::Move> + 'a
     where
         Self: 'a;
 
+    /// Why [`Game::make_move`] rejected a move.
+    ///
+    /// Games with their own failure modes should define their own error,
+    /// games whose moves can't fail can use [`core::convert::Infallible`],
+    /// and every other game can use [`DefaultMoveError`].
     type MoveError: Error;
 
     type Player: Player;
@@ -89,18 +270,151 @@ pub trait Game: Clone {
     fn move_count(&self) -> usize;
 
     /// Get the max number of moves in a game, if any.
+    ///
+    /// The solver needs this bound to score positions, and refuses to solve games without one
+    /// (see [`crate::GameSolveError::UnboundedGame`]). Loopy games that end on a repeated position
+    /// can bound this by their amount of distinct positions.
     fn max_moves(&self) -> Option<usize>;
 
+    /// The lowest score [`crate::solve`] should consider for the player to move,
+    /// or `None` if the game can't be scored. Defaults to [`min_score`].
+    ///
+    /// Together with [`Game::max_score`], this is the initial window `solve` narrows down
+    /// with null-window searches, so games that know their scores fall in a tighter range
+    /// can override both to converge in fewer iterations.
+    /// Scores outside of the range are never found, so the range must include the real score.
+    fn min_score(&self) -> Option<isize> {
+        min_score(self)
+    }
+
+    /// The highest score [`crate::solve`] should consider for the player to move,
+    /// or `None` if the game can't be scored. Defaults to [`max_score`].
+    ///
+    /// See [`Game::min_score`].
+    fn max_score(&self) -> Option<isize> {
+        max_score(self)
+    }
+
     /// Makes a move.
     fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError>;
 
+    /// Makes every move of `moves` in order, checking that each one is among
+    /// [`Game::possible_moves`] before making it.
+    ///
+    /// This is meant for move sequences from untrusted sources (e.g. a file, or the network),
+    /// as [`Game::make_move`] isn't required to reject every illegal move.
+    /// If a move fails, the moves before it stay made.
+    fn apply_move_sequence_checked<I: IntoIterator<Item = Self::Move>>(
+        &mut self,
+        moves: I,
+    ) -> Result<(), MoveSequenceError<Self>>
+    where
+        Self::Move: PartialEq,
+    {
+        for (index, m) in moves.into_iter().enumerate() {
+            if self.state().is_terminal() {
+                return Err(MoveSequenceError::IllegalMove(
+                    index,
+                    m,
+                    DefaultMoveError::GameAlreadyOver,
+                ));
+            }
+
+            if !self
+                .possible_moves()
+                .any(|possible| self.moves_equal(&possible, &m))
+            {
+                return Err(MoveSequenceError::IllegalMove(
+                    index,
+                    m,
+                    DefaultMoveError::IllegalMove,
+                ));
+            }
+
+            self.make_move(&m).map_err(MoveSequenceError::GameError)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns an iterator of all possible moves.
     ///
     /// If possible, this function should "guess" what the best moves are first.
     /// For example, if this is for tic tac toe, it should give the middle move first.
     /// Since "better" moves would be found first, this permits more alpha/beta cutoffs.
+    ///
+    /// Once the game is over (see [`GameState::is_terminal`]), this should be empty:
+    /// the solver never expands terminal positions, and in debug builds, asserts as much.
     fn possible_moves(&self) -> Self::Iter<'_>;
 
+    /// Every move of [`Game::possible_moves`], with the position it leads to.
+    ///
+    /// Moves that [`Game::make_move`] rejects are skipped, so code that needs to report
+    /// illegal moves should make moves itself.
+    fn children(&self) -> impl Iterator<Item = (Self::Move, Self)> + '_ {
+        self.possible_moves().filter_map(|m| {
+            let mut board = self.clone();
+            board.make_move(&m).ok()?;
+            Some((m, board))
+        })
+    }
+
+    /// Like [`Game::children`], along with the [`Game::state`] of every child.
+    fn children_states(
+        &self,
+    ) -> impl Iterator<Item = (Self::Move, Self, GameState<Self::Player>)> + '_ {
+        self.children().map(|(m, board)| {
+            let state = board.state();
+            (m, board, state)
+        })
+    }
+
+    /// Returns the moves of [`Game::possible_moves`], skipping moves that lead to
+    /// the same position as a move before them. This is what [`crate::solve`] searches,
+    /// so it doesn't explore the same subtree twice.
+    ///
+    /// By default, this doesn't skip any moves. Games that can cheaply tell which moves
+    /// are equivalent (e.g. a piece moving to its own square in some variants)
+    /// should override this. Skipping moves this way is only valid if the [`Eq`] and [`Hash`]
+    /// implementations of the game compare positions, so that equivalent moves really do
+    /// lead to the same position.
+    fn canonical_moves(&self) -> Self::Iter<'_> {
+        self.possible_moves()
+    }
+
+    /// Splits the [`Game::canonical_moves`] into the first move and the moves after it,
+    /// or `None` if there aren't any moves.
+    ///
+    /// This is how [`crate::ybwc_solve`] picks the move it searches before the others
+    /// (the "eldest brother"), so games with a cheap guess at the best move can override this
+    /// to put that move first without reordering the rest.
+    fn split_moves(&self) -> Option<(Self::Move, Self::Iter<'_>)> {
+        let mut moves = self.canonical_moves();
+        let first = moves.next()?;
+        Some((first, moves))
+    }
+
+    /// Returns true if this position is quiet: its score can be guessed without searching further,
+    /// as no move is about to swing it (e.g. a capture or a check in chess).
+    ///
+    /// Depth-limited searches (like [`crate::negamax_raw`] with a depth) score quiet positions
+    /// past their depth as ties, but keep searching the [`Game::noisy_moves`] of positions
+    /// that aren't quiet, until they settle down. Games that override this must make sure every
+    /// line of noisy moves ends, as these are searched regardless of the depth.
+    ///
+    /// By default, every position is quiet.
+    fn is_quiet(&self) -> bool {
+        true
+    }
+
+    /// Returns the moves that make this position noisy (see [`Game::is_quiet`]),
+    /// which are the only moves searched past the depth of a depth-limited search.
+    ///
+    /// By default, this is every move of [`Game::possible_moves`].
+    fn noisy_moves(&self) -> Self::Iter<'_> {
+        self.possible_moves()
+    }
+
     /// Returns a reachable game in one move.
     ///
     /// Rather, this function asks if there exists some game in the possible games set
@@ -116,13 +430,37 @@ pub trait Game: Clone {
     /// function's contract).
     ///
     /// This function's default implementation is quite slow,
-    /// and it's encouraged to use a custom implementation.
+    /// and it's encouraged to use a custom implementation
+    /// of [`Game::find_immediately_resolvable_game_among`], which this defers to.
     fn find_immediately_resolvable_game(&self) -> Result<Option<Self>, Self::MoveError> {
+        self.find_immediately_resolvable_game_among(&self.possible_moves().collect::<Vec<_>>())
+    }
+
+    /// The same as [`Game::find_immediately_resolvable_game`], where `moves` are
+    /// this position's [`Game::possible_moves`].
+    ///
+    /// The solver calls this with the moves it already generated for its search,
+    /// so moves are only generated once per position. Games that can find resolvable games
+    /// without scanning every move (e.g. from the last move played) are free to ignore `moves`.
+    fn find_immediately_resolvable_game_among(
+        &self,
+        moves: &[Self::Move],
+    ) -> Result<Option<Self>, Self::MoveError> {
+        if let Some(m) = moves.iter().find(|m| self.is_winning_move(m)) {
+            let mut new_self = self.clone();
+            new_self.make_move(m)?;
+            return Ok(Some(new_self));
+        }
+
         let mut best_non_winning_game: Option<Self> = None;
 
-        for m in &mut self.possible_moves() {
+        for m in moves {
             let mut new_self = self.clone();
-            new_self.make_move(&m)?;
+            new_self.make_move(m)?;
+            debug_assert!(
+                new_self.move_count() <= new_self.max_moves().unwrap_or(usize::MAX),
+                "move_count exceeded max_moves"
+            );
             match new_self.state() {
                 GameState::Playable => continue,
                 GameState::Tie => best_non_winning_game = Some(new_self),
@@ -139,6 +477,168 @@ pub trait Game: Clone {
         Ok(best_non_winning_game)
     }
 
+    /// Picks one of [`Game::possible_moves`] uniformly at random, e.g. for random playouts,
+    /// or returns `None` if there are no moves.
+    ///
+    /// Games that can pick a random move without generating every move
+    /// (e.g. from a bitboard of legal moves) should override this.
+    #[cfg(feature = "rand")]
+    fn random_move(&self, rng: &mut impl rand::Rng) -> Option<Self::Move> {
+        use rand::seq::IteratorRandom;
+
+        self.possible_moves().choose(rng)
+    }
+
+    /// Returns true if playing `m` wins the game right away for the player to move.
+    ///
+    /// The solver checks this for every move before searching a position, as does the default
+    /// [`Game::find_immediately_resolvable_game_among`] before making any move.
+    /// Games that can detect a win without making the move
+    /// (e.g. by checking for four in a row around the dropped piece in Connect Four)
+    /// should override this.
+    ///
+    /// By default, this clones the game, makes the move, and checks if the player who made it won.
+    fn is_winning_move(&self, m: &Self::Move) -> bool {
+        let mut board = self.clone();
+        board.make_move(m).is_ok() && board.state() == GameState::Win(self.player().turn())
+    }
+
+    /// Returns the score of playing `m` (from the perspective of the player playing it)
+    /// if it wins the game right away, without making the move.
+    ///
+    /// Winning right away always scores `max_score(self) - 1`,
+    /// since the resulting position is lost for the player to move in it.
+    /// Games that can detect winning moves cheaply (e.g. from the last move played)
+    /// should override this, as the solver checks it for every move before cloning
+    /// into any position; it then skips the rest of the search.
+    ///
+    /// By default, no move is known to win, and this returns `None`.
+    fn score_after_winning_move(&self, m: &Self::Move) -> Option<isize> {
+        let _ = m;
+        None
+    }
+
+    /// Maps a move of this game to an index in `0..move_to_index_count()`,
+    /// so tables keyed by moves (e.g. a [`crate::heuristic::HistoryTable`])
+    /// can store them in a flat `Vec` instead of a `HashMap`.
+    ///
+    /// Every move must map to a distinct index, regardless of the position it's played in.
+    /// By default, moves have no index, and this returns `None`.
+    fn move_to_index(&self, m: &Self::Move) -> Option<usize> {
+        let _ = m;
+        None
+    }
+
+    /// The inverse of [`Game::move_to_index`]: returns the move with this index,
+    /// or `None` if no move has it.
+    fn move_from_index(&self, index: usize) -> Option<Self::Move> {
+        let _ = index;
+        None
+    }
+
+    /// The amount of move indices, i.e. one more than the largest index
+    /// [`Game::move_to_index`] can return.
+    ///
+    /// By default, moves have no index, and this returns `None`.
+    fn move_to_index_count(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the canonical representative of this position's symmetry class.
+    ///
+    /// Many games have symmetries (reflections, rotations) that produce equivalent positions.
+    /// If every symmetric position returns the same canonical form,
+    /// transposition tables wrapped in a [`crate::transposition::SymmetryAwareTable`]
+    /// can share one entry across the entire symmetry class.
+    ///
+    /// The canonical form must have the same score, move count, and player as the original position.
+    ///
+    /// By default, every position is its own canonical form.
+    fn canonical_form(&self) -> Self {
+        self.clone()
+    }
+
+    /// Formats a move for display, e.g. `"column 4"` for Connect Four.
+    ///
+    /// This lets generic code show moves without requiring `Self::Move: Display`,
+    /// which not every move type has a natural implementation for.
+    ///
+    /// By default, this uses the move's [`Debug`](core::fmt::Debug) representation.
+    fn display_move(&self, m: &Self::Move) -> String
+    where
+        Self::Move: core::fmt::Debug,
+    {
+        format!("{m:?}")
+    }
+
+    /// Whether `a` and `b` are the same move.
+    ///
+    /// Generic code compares moves through this, so games whose moves are expensive to compare
+    /// (e.g. moves stored as strings) can override it with a cheaper comparison.
+    ///
+    /// By default, this uses the move's [`PartialEq`] implementation.
+    fn moves_equal(&self, a: &Self::Move, b: &Self::Move) -> bool
+    where
+        Self::Move: PartialEq,
+    {
+        a == b
+    }
+
+    /// Returns true if the game has ended in a draw.
+    ///
+    /// The solver checks this before [`Game::state`], so games that can detect draws cheaply
+    /// (a full board, a move counter running out) should override this,
+    /// rather than paying for a full win check.
+    ///
+    /// By default, this checks if [`Game::state`] is a [`GameState::Tie`].
+    fn is_draw(&self) -> bool {
+        self.state() == GameState::Tie
+    }
+
+    /// Returns true if this position has already been reached earlier in the game,
+    /// in which case the solver treats it as a draw.
+    ///
+    /// This is needed for games that can loop forever (e.g. chess),
+    /// as otherwise the solver would recurse infinitely.
+    /// Games that track their history (or a Zobrist hash of it) can implement this efficiently,
+    /// for example with a [`crate::loopy::RepeatedPositionDetector`].
+    ///
+    /// Since repetitions depend on the path to a position, [`crate::solve`] doesn't store
+    /// scores that depend on one in its transposition table.
+    ///
+    /// By default, no position is ever considered repeated.
+    fn is_repeated_position(&self) -> bool {
+        false
+    }
+
+    /// Returns how many times the current position has occurred in the game so far,
+    /// for games with a three-fold repetition rule (e.g. chess).
+    ///
+    /// This is only meaningful for games whose positions can repeat.
+    /// Games that keep a history of Zobrist hashes can count occurrences of the current one;
+    /// by default, no repetitions are detected and this returns 0.
+    fn repetition_count(&self) -> usize {
+        0
+    }
+
+    /// Returns true if the current position has occurred three or more times,
+    /// which the solver treats as a draw (see [`Game::repetition_count`]).
+    fn is_repetition_draw(&self) -> bool {
+        self.repetition_count() >= 3
+    }
+
+    /// Scores this ended position for `maximizer`, for searches that don't negate scores
+    /// between players (see [`crate::minimax`]).
+    ///
+    /// Games that aren't strictly zero-sum (where one player's gain isn't exactly the other's loss)
+    /// can override this to value wins differently depending on who wins.
+    /// Returning `None` uses the usual score: [`max_score`] for a win by `maximizer`,
+    /// its negation for a loss, and 0 for a tie.
+    fn evaluate(&self, maximizer: Self::Player) -> Option<isize> {
+        let _ = maximizer;
+        None
+    }
+
     /// Returns the current state of the game.
     /// Used for verifying initialization and is commonly called.
     ///
@@ -182,6 +682,97 @@ pub trait Game: Clone {
     /// However, no implementation is provided
     /// because this does not keep track of the move count.
     fn player(&self) -> Self::Player;
+
+    /// Some position of this game that has ended in `state`, to test how ended games are handled
+    /// without playing one out by hand.
+    ///
+    /// Returns `None` if `state` isn't terminal, or if the game can't construct
+    /// such a position without more information (e.g. a board size).
+    /// Which position is returned is up to the game. By default, this is always `None`.
+    fn terminal_game(state: GameState<Self::Player>) -> Option<Self> {
+        let _ = state;
+        None
+    }
+}
+
+/// A general-purpose [`Game::MoveError`], for games that don't need their own.
+///
+/// Associated types can't have defaults yet, so this has to be chosen explicitly,
+/// with `type MoveError = DefaultMoveError;`.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefaultMoveError {
+    /// The move refers to something outside of the game, e.g. a square off the board.
+    #[error("the move is out of bounds")]
+    OutOfBounds,
+    /// The move exists, but can't be played in this position.
+    #[error("the move is illegal in this position")]
+    IllegalMove,
+    /// No more moves can be made, as the game is over.
+    #[error("the game is already over")]
+    GameAlreadyOver,
+}
+
+/// An error from [`Game::apply_move_sequence_checked`].
+#[derive(Error, Debug)]
+pub enum MoveSequenceError<T: Game> {
+    /// The move at this index of the sequence couldn't be played: either it wasn't
+    /// a possible move ([`DefaultMoveError::IllegalMove`]), or the game was already over
+    /// ([`DefaultMoveError::GameAlreadyOver`]).
+    #[error("move {0} of the sequence can't be played: {2}")]
+    IllegalMove(usize, T::Move, DefaultMoveError),
+    #[error("could not make a move")]
+    GameError(T::MoveError),
+}
+
+/// A game whose [`Game::possible_moves`] always knows its exact length.
+///
+/// This is an opt-in marker: implement it (with no methods) for games whose
+/// [`Game::Iter`] is an [`ExactSizeIterator`], so that [`move_count_exact`] can count moves
+/// without iterating over them, and so that the solver's size hints
+/// (e.g. when collecting move scores) are exact.
+pub trait ExactGame: Game
+where
+    for<'a> Self::Iter<'a>: ExactSizeIterator,
+{
+}
+
+/// The amount of possible moves in `game`, without iterating over them.
+pub fn move_count_exact<T: ExactGame>(game: &T) -> usize
+where
+    for<'a> T::Iter<'a>: ExactSizeIterator,
+{
+    game.possible_moves().len()
+}
+
+/// Plays `m` on `game`, only cloning it if it's borrowed.
+pub(crate) fn play<T: Game>(game: Cow<'_, T>, m: &T::Move) -> Result<T, T::MoveError> {
+    let mut board = game.into_owned();
+    board.make_move(m)?;
+    Ok(board)
+}
+
+/// Plays each of `moves` on its own copy of `game`, in order.
+///
+/// Every move but the last needs a clone of `game`, but the last move is played
+/// on `game` itself if it's owned, which saves a clone per expanded position.
+#[cfg(feature = "std")]
+pub(crate) fn play_each<'a, T: Game + 'a>(
+    game: Cow<'a, T>,
+    moves: Vec<T::Move>,
+) -> impl Iterator<Item = Result<(T::Move, T), T::MoveError>> + 'a {
+    let last = moves.len().saturating_sub(1);
+    let mut game = Some(game);
+
+    moves.into_iter().enumerate().map(move |(i, m)| {
+        let parent = if i == last {
+            game.take().expect("the last move is only played once")
+        } else {
+            Cow::Borrowed(game.as_deref().expect("moves are played in order"))
+        };
+
+        let board = play(parent, &m)?;
+        Ok((m, board))
+    })
 }
 
 /// Utility function to get the upper score bound of a game.
@@ -191,16 +782,35 @@ pub trait Game: Clone {
 /// trying to encourage winning in the shortest amount of time - God's algorithm.
 ///
 /// Note: Despite this returning isize, this function will always be positive.
-pub fn upper_bound<T: Game>(game: &T) -> isize {
-    game.max_moves().map_or(
-        // TODO(HACKY): theres probably nicer ways of handling upper bounds for
-        // loopy games
-        isize::MAX / 2, |m| m as isize
-    )
+///
+/// Returns `None` for games without [`Game::max_moves`], which can't be scored.
+pub fn upper_bound<T: Game>(game: &T) -> Option<isize> {
+    game.max_moves().map(|m| m as isize)
+}
+
+/// The highest score the player to move can get in this game:
+/// winning right away, with the current move count.
+///
+/// This is derived from [`upper_bound`], and is only meaningful for zero-sum two-player games,
+/// where the score of one player is the negation of the score of the other.
+pub fn max_score<T: Game>(game: &T) -> Option<isize> {
+    Some(upper_bound(game)? - game.move_count() as isize + 1)
+}
+
+/// The lowest score the player to move can get in this game: losing right away.
+///
+/// Like [`max_score`], this is only meaningful for zero-sum two-player games.
+pub fn min_score<T: Game>(game: &T) -> Option<isize> {
+    max_score(game).map(|score| -score)
+}
+
+/// Every score the player to move can get in this game, i.e. `min_score..=max_score`.
+pub fn score_bounds<T: Game>(game: &T) -> Option<RangeInclusive<isize>> {
+    Some(min_score(game)?..=max_score(game)?)
 }
 
 /// Represents an outcome of a game derived by a score and a valid instance of a game.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameScoreOutcome {
     /// The inner field represents the amount of moves till a win.
     Win(usize),
@@ -209,16 +819,517 @@ pub enum GameScoreOutcome {
     Tie,
 }
 
+impl GameScoreOutcome {
+    /// Returns the amount of moves till the game is won or lost, or `None` for a tie.
+    pub const fn moves_remaining(&self) -> Option<usize> {
+        match self {
+            Self::Win(moves) | Self::Loss(moves) => Some(*moves),
+            Self::Tie => None,
+        }
+    }
+
+    pub const fn is_win(&self) -> bool {
+        matches!(self, Self::Win(_))
+    }
+
+    pub const fn is_loss(&self) -> bool {
+        matches!(self, Self::Loss(_))
+    }
+
+    pub const fn is_tie(&self) -> bool {
+        matches!(self, Self::Tie)
+    }
+}
+
+/// `Some(true)` for a win, `Some(false)` for a loss, and `None` for a tie.
+impl From<GameScoreOutcome> for Option<bool> {
+    fn from(outcome: GameScoreOutcome) -> Self {
+        match outcome {
+            GameScoreOutcome::Win(_) => Some(true),
+            GameScoreOutcome::Loss(_) => Some(false),
+            GameScoreOutcome::Tie => None,
+        }
+    }
+}
+
 /// Utility function to convert a score to the
 /// amount of moves to a win or loss, or a tie.
-pub fn score_to_outcome<T: Game>(game: &T, score: isize) -> GameScoreOutcome {
-    match score.cmp(&0) {
-        Ordering::Greater => GameScoreOutcome::Win(
-            (-score + upper_bound(game) - game.move_count() as isize) as usize,
-        ),
+///
+/// Returns `None` for games without an [`upper_bound`], whose scores aren't defined.
+///
+/// Games whose move count exceeds their [`Game::max_moves`] are broken: this asserts
+/// against them in debug builds, and the amount of moves saturates at 0 otherwise.
+pub fn score_to_outcome<T: Game>(game: &T, score: isize) -> Option<GameScoreOutcome> {
+    debug_assert!(
+        game.move_count() <= game.max_moves().unwrap_or(usize::MAX),
+        "move_count exceeded max_moves"
+    );
+
+    let upper_bound = upper_bound(game)?;
+
+    let moves_left =
+        |score: isize| (score + upper_bound - game.move_count() as isize).max(0) as usize;
+
+    Some(match score.cmp(&0) {
+        Ordering::Greater => GameScoreOutcome::Win(moves_left(-score)),
         Ordering::Equal => GameScoreOutcome::Tie,
-        Ordering::Less => GameScoreOutcome::Loss(
-            (score + upper_bound(game) - game.move_count() as isize) as usize,
-        ),
+        Ordering::Less => GameScoreOutcome::Loss(moves_left(score)),
+    })
+}
+
+/// The inverse of [`score_to_outcome`]: the score of `outcome` in `game`.
+///
+/// Returns `None` for games without an [`upper_bound`], whose scores aren't defined.
+///
+/// Outcomes round-trip through [`score_to_outcome`] as long as they're reachable,
+/// i.e. they don't take more moves than `game` has left.
+pub fn moves_remaining_to_score<T: Game>(game: &T, outcome: GameScoreOutcome) -> Option<isize> {
+    let moves_left = upper_bound(game)? - game.move_count() as isize;
+
+    Some(match outcome {
+        GameScoreOutcome::Win(moves) => moves_left - moves as isize,
+        GameScoreOutcome::Tie => 0,
+        GameScoreOutcome::Loss(moves) => moves as isize - moves_left,
+    })
+}
+
+/// Maps `score` from the [`score_bounds`] of `game` to `[-1.0, 1.0]`,
+/// so that scores of different games (or of positions with different move counts) can be compared.
+///
+/// Returns `None` for games without a [`max_score`].
+pub fn normalize_score<T: Game>(game: &T, score: isize) -> Option<f64> {
+    let max_score = game.max_score()?;
+    let min_score = game.min_score()?;
+
+    // (a game with a single possible score can only ever be tied)
+    if max_score == min_score {
+        return Some(0.0);
+    }
+
+    Some(2.0 * (score - min_score) as f64 / (max_score - min_score) as f64 - 1.0)
+}
+
+/// How a [`ScoreDisplay`] renders its score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreDisplayFormat {
+    /// The score itself, e.g. `3`.
+    Raw,
+    /// `Win`, `Loss`, or `Draw`.
+    WinLossTie,
+    /// The amount of moves until the game is decided, e.g. `+M3` or `-M5`, and `0` for a draw.
+    MateInN,
+    /// The score multiplied by the inner factor and rendered in hundredths, e.g. `+1.50`,
+    /// following the convention of chess engines.
+    Centipawn(isize),
+}
+
+/// A score, rendered for end users in some [`ScoreDisplayFormat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreDisplay {
+    pub score: isize,
+    pub game_outcome: GameScoreOutcome,
+    pub format: ScoreDisplayFormat,
+}
+
+impl Display for ScoreDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.format, self.game_outcome) {
+            (ScoreDisplayFormat::Raw, _) => write!(f, "{}", self.score),
+            (ScoreDisplayFormat::WinLossTie, GameScoreOutcome::Win(_)) => write!(f, "Win"),
+            (ScoreDisplayFormat::WinLossTie, GameScoreOutcome::Loss(_)) => write!(f, "Loss"),
+            (ScoreDisplayFormat::WinLossTie, GameScoreOutcome::Tie) => write!(f, "Draw"),
+            (ScoreDisplayFormat::MateInN, GameScoreOutcome::Win(moves)) => write!(f, "+M{moves}"),
+            (ScoreDisplayFormat::MateInN, GameScoreOutcome::Loss(moves)) => write!(f, "-M{moves}"),
+            (ScoreDisplayFormat::MateInN, GameScoreOutcome::Tie) => write!(f, "0"),
+            (ScoreDisplayFormat::Centipawn(factor), _) => {
+                let centipawns = self.score.saturating_mul(factor);
+                let sign = match centipawns.cmp(&0) {
+                    Ordering::Greater => "+",
+                    Ordering::Equal => "",
+                    Ordering::Less => "-",
+                };
+                let centipawns = centipawns.unsigned_abs();
+
+                write!(f, "{sign}{}.{:02}", centipawns / 100, centipawns % 100)
+            }
+        }
+    }
+}
+
+/// Prepares `score` (of the player to move in `game`) to be rendered in the given format.
+///
+/// Returns `None` for games without an [`upper_bound`], like [`score_to_outcome`].
+pub fn score_display<T: Game>(
+    game: &T,
+    score: isize,
+    format: ScoreDisplayFormat,
+) -> Option<ScoreDisplay> {
+    Some(ScoreDisplay {
+        score,
+        game_outcome: score_to_outcome(game, score)?,
+        format,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::PartizanPlayer;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display_game_state() {
+        assert_eq!(
+            GameState::<PartizanPlayer>::Playable.to_string(),
+            "Game in progress"
+        );
+        assert_eq!(GameState::<PartizanPlayer>::Tie.to_string(), "Tie");
+        assert_eq!(
+            GameState::Win(PartizanPlayer::Left).to_string(),
+            "Left wins"
+        );
+    }
+
+    #[test]
+    fn game_state_winners() {
+        assert_eq!(
+            Option::from(GameState::Win(PartizanPlayer::Right)),
+            Some(PartizanPlayer::Right)
+        );
+        assert_eq!(Option::<PartizanPlayer>::from(GameState::Tie), None);
+        assert_eq!(Option::<PartizanPlayer>::from(GameState::Playable), None);
+
+        assert_eq!(
+            GameState::Win(PartizanPlayer::Left)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [PartizanPlayer::Left]
+        );
+        assert_eq!(GameState::<PartizanPlayer>::Tie.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn display_scores() {
+        let display = |score, game_outcome, format| {
+            ScoreDisplay {
+                score,
+                game_outcome,
+                format,
+            }
+            .to_string()
+        };
+
+        assert_eq!(
+            display(4, GameScoreOutcome::Win(3), ScoreDisplayFormat::Raw),
+            "4"
+        );
+        assert_eq!(
+            display(-2, GameScoreOutcome::Loss(5), ScoreDisplayFormat::Raw),
+            "-2"
+        );
+
+        assert_eq!(
+            display(4, GameScoreOutcome::Win(3), ScoreDisplayFormat::WinLossTie),
+            "Win"
+        );
+        assert_eq!(
+            display(
+                -2,
+                GameScoreOutcome::Loss(5),
+                ScoreDisplayFormat::WinLossTie
+            ),
+            "Loss"
+        );
+        assert_eq!(
+            display(0, GameScoreOutcome::Tie, ScoreDisplayFormat::WinLossTie),
+            "Draw"
+        );
+
+        assert_eq!(
+            display(4, GameScoreOutcome::Win(3), ScoreDisplayFormat::MateInN),
+            "+M3"
+        );
+        assert_eq!(
+            display(-2, GameScoreOutcome::Loss(5), ScoreDisplayFormat::MateInN),
+            "-M5"
+        );
+        assert_eq!(
+            display(0, GameScoreOutcome::Tie, ScoreDisplayFormat::MateInN),
+            "0"
+        );
+
+        let centipawn = ScoreDisplayFormat::Centipawn(50);
+        assert_eq!(display(3, GameScoreOutcome::Win(1), centipawn), "+1.50");
+        assert_eq!(display(-1, GameScoreOutcome::Loss(1), centipawn), "-0.50");
+        assert_eq!(display(0, GameScoreOutcome::Tie, centipawn), "0.00");
+    }
+
+    #[test]
+    fn score_outcome_helpers() {
+        let win = GameScoreOutcome::Win(3);
+        let loss = GameScoreOutcome::Loss(2);
+        let tie = GameScoreOutcome::Tie;
+
+        assert_eq!(win.moves_remaining(), Some(3));
+        assert_eq!(loss.moves_remaining(), Some(2));
+        assert_eq!(tie.moves_remaining(), None);
+
+        assert!(win.is_win() && !win.is_loss() && !win.is_tie());
+        assert!(loss.is_loss() && !loss.is_win() && !loss.is_tie());
+        assert!(tie.is_tie() && !tie.is_win() && !tie.is_loss());
+
+        assert_eq!(Option::<bool>::from(win), Some(true));
+        assert_eq!(Option::<bool>::from(loss), Some(false));
+        assert_eq!(Option::<bool>::from(tie), None);
+    }
+
+    #[test]
+    fn game_state_outcome() {
+        assert!(!GameState::<PartizanPlayer>::Playable.is_terminal());
+        assert!(GameState::<PartizanPlayer>::Tie.is_terminal());
+        assert!(GameState::Win(PartizanPlayer::Left).is_terminal());
+
+        assert_eq!(GameState::<PartizanPlayer>::Playable.winner(), None);
+        assert_eq!(GameState::<PartizanPlayer>::Tie.winner(), None);
+        assert_eq!(
+            GameState::Win(PartizanPlayer::Right).winner(),
+            Some(&PartizanPlayer::Right)
+        );
+    }
+
+    // (these use the Nim test game, which needs `std`)
+    #[cfg(feature = "std")]
+    mod nim {
+        use super::*;
+        use crate::{
+            player::ImpartialPlayer,
+            tests::{over_counted, Nim},
+        };
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "move_count exceeded max_moves")]
+        fn over_counted_outcomes_fail_fast() {
+            score_to_outcome(&over_counted(), 1);
+        }
+
+        #[test]
+        #[cfg(not(debug_assertions))]
+        fn over_counted_outcomes_saturate() {
+            assert_eq!(
+                score_to_outcome(&over_counted(), 1),
+                Some(GameScoreOutcome::Win(0))
+            );
+            assert_eq!(
+                score_to_outcome(&over_counted(), -1),
+                Some(GameScoreOutcome::Loss(0))
+            );
+        }
+
+        #[test]
+        fn outcomes_round_trip_to_scores() {
+            let nim = Nim::new(vec![2, 3, 4]);
+
+            for score in score_bounds(&nim).unwrap() {
+                // (the highest scores are only given to ended games, and saturate to the same outcome)
+                if score.abs() == max_score(&nim).unwrap() {
+                    continue;
+                }
+
+                let outcome = score_to_outcome(&nim, score).unwrap();
+                assert_eq!(
+                    moves_remaining_to_score(&nim, outcome),
+                    Some(score),
+                    "{outcome:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn normalized_scores() {
+            let nim = Nim::new(vec![2, 3, 4]);
+            let max_score = max_score(&nim).unwrap();
+
+            assert_eq!(normalize_score(&nim, max_score), Some(1.0));
+            assert_eq!(normalize_score(&nim, -max_score), Some(-1.0));
+            assert_eq!(normalize_score(&nim, 0), Some(0.0));
+            assert!(normalize_score(&nim, 1).unwrap() > 0.0);
+        }
+
+        #[test]
+        fn exact_move_counts() {
+            let nim = Nim::new(vec![1, 2, 3]);
+            assert_eq!(move_count_exact(&nim), nim.possible_moves().count());
+        }
+
+        impl WinnerDetector for Nim {
+            fn current_player_wins(&self) -> bool {
+                false
+            }
+
+            fn previous_player_wins(&self) -> bool {
+                self.heaps.iter().all(|&heap| heap == 0)
+            }
+        }
+
+        #[test]
+        fn winner_detectors_match_state() {
+            for heaps in [vec![0], vec![0, 0], vec![1, 0], vec![2, 3]] {
+                let nim = Nim::new(heaps);
+                assert_eq!(<Nim as WinnerDetector>::state(&nim), Game::state(&nim));
+            }
+        }
+
+        #[test]
+        fn state_types() {
+            let playing = Nim::new(vec![1, 2]);
+            let over = Nim::new(vec![0, 0]);
+
+            assert_eq!(StateType::Normal.state(&playing), GameState::Playable);
+            assert_eq!(StateType::Misere.state(&playing), GameState::Playable);
+            assert_eq!(StateType::Normal.state(&over), Game::state(&over));
+            assert_eq!(
+                StateType::Misere.state(&over),
+                GameState::Win(ImpartialPlayer::Next)
+            );
+
+            // a convention where clearing a single heap ties the game.
+            let custom = StateType::Custom(Arc::new(|nim: &Nim| {
+                if Game::state(nim).is_terminal() {
+                    Game::state(nim)
+                } else if nim.heaps.iter().any(|&heap| heap == 0) {
+                    GameState::Tie
+                } else {
+                    GameState::Playable
+                }
+            }));
+            assert_eq!(custom.clone().state(&Nim::new(vec![0, 2])), GameState::Tie);
+            assert_eq!(custom.state(&playing), GameState::Playable);
+            assert_eq!(custom.state(&over), Game::state(&over));
+        }
+
+        #[test]
+        fn children_are_reachable_positions() {
+            let nim = Nim::new(vec![1, 2]);
+            let children = nim.children_states().collect::<Vec<_>>();
+            assert_eq!(children.len(), nim.possible_moves().count());
+
+            for (m, board, state) in children {
+                let mut expected = nim.clone();
+                expected.make_move(&m).unwrap();
+                assert_eq!(board, expected);
+                assert_eq!(state, Game::state(&board));
+            }
+
+            // empty heaps have no moves.
+            let nim = Nim::new(vec![0, 1]);
+            assert_eq!(nim.children().map(|(m, _)| m).collect::<Vec<_>>(), [(1, 1)]);
+        }
+
+        #[test]
+        #[cfg(feature = "rand")]
+        fn random_moves_are_possible() {
+            use rand::{rngs::StdRng, SeedableRng};
+
+            let mut rng = StdRng::seed_from_u64(0);
+            let mut nim = Nim::new(vec![2, 3, 4]);
+            while let Some(m) = nim.random_move(&mut rng) {
+                assert!(nim.possible_moves().any(|possible| possible == m));
+                nim.make_move(&m).unwrap();
+            }
+
+            assert_eq!(nim.possible_moves().next(), None);
+            assert!(Game::state(&nim).is_terminal());
+        }
+
+        #[test]
+        fn move_sequences_are_checked() {
+            let mut nim = Nim::new(vec![2, 3]);
+            nim.apply_move_sequence_checked([(0, 1), (1, 3)]).unwrap();
+            assert_eq!(nim.heaps, vec![1, 0]);
+
+            assert!(matches!(
+                nim.apply_move_sequence_checked([(1, 1)]),
+                Err(MoveSequenceError::IllegalMove(
+                    0,
+                    (1, 1),
+                    DefaultMoveError::IllegalMove
+                ))
+            ));
+
+            assert!(matches!(
+                nim.apply_move_sequence_checked([(0, 1), (1, 1)]),
+                Err(MoveSequenceError::IllegalMove(
+                    1,
+                    (1, 1),
+                    DefaultMoveError::GameAlreadyOver
+                ))
+            ));
+            assert_eq!(nim.heaps, vec![0, 0]);
+        }
+
+        #[test]
+        fn moves_equal_defaults_to_eq() {
+            let nim = Nim::new(vec![2, 3]);
+            assert!(nim.moves_equal(&(0, 1), &(0, 1)));
+            assert!(!nim.moves_equal(&(0, 1), &(1, 1)));
+        }
+
+        /// A subtraction game, where players take one or two tokens in turn, and taking the last wins.
+        #[cfg(feature = "derive")]
+        #[derive(Game, Clone, Debug, PartialEq, Eq, Hash)]
+        #[game(max_moves = 10, state_type = "Normal")]
+        struct Subtraction {
+            tokens: usize,
+            #[game(move_count)]
+            moves: usize,
+        }
+
+        #[cfg(feature = "derive")]
+        impl GameRules for Subtraction {
+            type Move = usize;
+            type Iter<'a> = core::ops::RangeInclusive<usize>;
+            type MoveError = DefaultMoveError;
+
+            fn moves(&self) -> Self::Iter<'_> {
+                1..=self.tokens.min(2)
+            }
+
+            fn apply_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+                self.tokens = self
+                    .tokens
+                    .checked_sub(*m)
+                    .ok_or(DefaultMoveError::IllegalMove)?;
+                Ok(())
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "derive")]
+        fn derived_games() {
+            use std::collections::HashMap;
+
+            use crate::solve;
+
+            let mut game = Subtraction {
+                tokens: 4,
+                moves: 0,
+            };
+            assert_eq!(game.player(), PartizanPlayer::Left);
+            assert_eq!(game.max_moves(), Some(10));
+
+            game.make_move(&1).unwrap();
+            assert_eq!(game.move_count(), 1);
+            assert_eq!(game.player(), PartizanPlayer::Right);
+            assert!(game.make_move(&9).is_err());
+            assert_eq!(game.move_count(), 1);
+
+            // multiples of three are lost for the player to move.
+            for tokens in 1..=10 {
+                let game = Subtraction { tokens, moves: 0 };
+                let score = solve(&game, &mut HashMap::new(), None).unwrap();
+                assert_eq!(score > 0, tokens % 3 != 0, "{tokens} tokens");
+            }
+        }
     }
 }
@@ -0,0 +1,4 @@
+//! Games bundled with the solver, as reference implementations of [`crate::game::Game`]
+//! and as test fixtures whose solutions are known.
+
+pub mod nim;
@@ -0,0 +1,140 @@
+//! [Nim](https://en.wikipedia.org/wiki/Nim), where players take turns removing objects from heaps,
+//! and whoever takes the last object wins.
+//!
+//! Nim is solved analytically: the player to move wins if and only if
+//! the [nim-sum](NimGame::nim_sum) of the heaps is nonzero, which makes it easy to check
+//! the solver against.
+
+use alloc::vec::Vec;
+
+use crate::{
+    game::{DefaultMoveError, ExactGame, Game, GameState, Normal, NormalImpartial},
+    player::ImpartialPlayer,
+};
+
+/// A game of Nim, with the amount of objects left in each heap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NimGame {
+    pub heaps: Vec<usize>,
+    pub move_count: usize,
+}
+
+impl NimGame {
+    /// A new game with `heaps`, where every heap is the amount of objects in it.
+    pub fn new(heaps: Vec<usize>) -> Self {
+        Self {
+            heaps,
+            move_count: 0,
+        }
+    }
+
+    /// The XOR of every heap, which is nonzero exactly when the player to move can win.
+    pub fn nim_sum(&self) -> usize {
+        self.heaps.iter().fold(0, |sum, heap| sum ^ heap)
+    }
+}
+
+impl Normal for NimGame {}
+impl NormalImpartial for NimGame {}
+impl ExactGame for NimGame {}
+
+impl Game for NimGame {
+    /// The heap to take from, and how many objects to take.
+    type Move = (usize, usize);
+    type Iter<'a> = alloc::vec::IntoIter<Self::Move>;
+    type MoveError = DefaultMoveError;
+    type Player = ImpartialPlayer;
+
+    fn move_count(&self) -> usize {
+        self.move_count
+    }
+
+    fn max_moves(&self) -> Option<usize> {
+        // every move takes at least one object.
+        Some(self.move_count + self.heaps.iter().sum::<usize>())
+    }
+
+    fn make_move(&mut self, &(heap, amount): &Self::Move) -> Result<(), Self::MoveError> {
+        let heap = self
+            .heaps
+            .get_mut(heap)
+            .ok_or(DefaultMoveError::OutOfBounds)?;
+
+        if amount == 0 || amount > *heap {
+            return Err(DefaultMoveError::IllegalMove);
+        }
+
+        *heap -= amount;
+        self.move_count += 1;
+        Ok(())
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        self.heaps
+            .iter()
+            .enumerate()
+            .flat_map(|(heap, &objects)| (1..=objects).map(move |amount| (heap, amount)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        <Self as Normal>::state(self)
+    }
+
+    fn player(&self) -> Self::Player {
+        ImpartialPlayer::Next
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{game::score_bounds, move_scores, solve};
+
+    /// Small enough games to solve quickly, including finished ones.
+    fn heaps() -> impl Strategy<Value = Vec<usize>> {
+        prop::collection::vec(0..=4usize, 0..=3)
+    }
+
+    proptest! {
+        #[test]
+        fn solve_matches_nim_sum(heaps in heaps()) {
+            let game = NimGame::new(heaps);
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+            prop_assert!(score_bounds(&game).unwrap().contains(&score));
+
+            // nim can't be tied, so positions that aren't won are lost.
+            prop_assert_eq!(score > 0, game.nim_sum() != 0);
+            prop_assert_ne!(score, 0);
+        }
+
+        #[test]
+        fn winning_moves_clear_the_nim_sum(heaps in heaps()) {
+            let game = NimGame::new(heaps);
+            let mut transposition_table = HashMap::new();
+
+            for result in move_scores(&game, &mut transposition_table, None) {
+                let (m, score) = result.unwrap();
+                let mut board = game.clone();
+                board.make_move(&m).unwrap();
+
+                prop_assert_eq!(score > 0, board.nim_sum() == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn illegal_moves_are_rejected() {
+        let mut game = NimGame::new(vec![2]);
+
+        assert_eq!(game.make_move(&(1, 1)), Err(DefaultMoveError::OutOfBounds));
+        assert_eq!(game.make_move(&(0, 3)), Err(DefaultMoveError::IllegalMove));
+        assert_eq!(game.make_move(&(0, 0)), Err(DefaultMoveError::IllegalMove));
+        assert_eq!(game.move_count, 0);
+    }
+}
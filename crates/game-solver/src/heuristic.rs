@@ -0,0 +1,192 @@
+//! Move ordering heuristics.
+//!
+//! Alpha-beta pruning cuts off the most branches when the best moves are searched first.
+//! [`Game::possible_moves`] already gives the game's own guess at a good order,
+//! but a [`MoveOrderer`] can refine it with information gathered by the solver.
+
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::game::Game;
+#[cfg(feature = "std")]
+use crate::{
+    player::TwoPlayer, shallow_negamax, stats::Stats, transposition::TranspositionTable,
+    GameSolveError,
+};
+
+/// A strategy for reordering the moves of a position before they're searched.
+pub trait MoveOrderer<T: Game> {
+    /// Reorders `moves` (the possible moves of `game`) so that the most promising come first.
+    fn order_moves(&self, game: &T, moves: &mut [T::Move]);
+}
+
+/// Keeps the order given by [`Game::possible_moves`]. This is the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoMoveOrderer;
+
+impl<T: Game> MoveOrderer<T> for NoMoveOrderer {
+    fn order_moves(&self, _game: &T, _moves: &mut [T::Move]) {}
+}
+
+/// Orders moves by precomputed scores, searching moves with higher scores first.
+///
+/// These scores are looked up by move alone, regardless of the position,
+/// so they work best for games where a move is similarly good across positions
+/// (e.g. the center column in Connect Four).
+/// Moves without a score are searched last, in their original order.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct WeightedMoveOrderer<T: Game>
+where
+    T::Move: Hash + Eq,
+{
+    scores: HashMap<T::Move, isize>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Game> WeightedMoveOrderer<T>
+where
+    T::Move: Hash + Eq,
+{
+    pub fn new(scores: HashMap<T::Move, isize>) -> Self {
+        Self { scores }
+    }
+
+    pub fn scores(&self) -> &HashMap<T::Move, isize> {
+        &self.scores
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash> WeightedMoveOrderer<T>
+where
+    T::Move: Hash + Eq,
+{
+    /// Scores every move of `game` with a search `depth` moves deep.
+    ///
+    /// As with [`crate::opening_moves`], positions past the depth are scored as ties,
+    /// and nothing derived from them is stored in the transposition table.
+    pub fn from_shallow_search(
+        game: &T,
+        depth: usize,
+        transposition_table: &mut dyn TranspositionTable<T>,
+        stats: Option<&Stats<T::Player>>,
+    ) -> Result<Self, GameSolveError<T>> {
+        let scores = game
+            .possible_moves()
+            .map(|m| {
+                let mut board = game.clone();
+                board
+                    .make_move(&m)
+                    .map_err(|err| GameSolveError::MoveError(err))?;
+
+                let score =
+                    -shallow_negamax(&board, transposition_table, depth.saturating_sub(1), stats)?;
+
+                Ok((m, score))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { scores })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Game> MoveOrderer<T> for WeightedMoveOrderer<T>
+where
+    T::Move: Hash + Eq,
+{
+    fn order_moves(&self, _game: &T, moves: &mut [T::Move]) {
+        moves.sort_by_key(|m| core::cmp::Reverse(self.scores.get(m).copied()));
+    }
+}
+
+/// Where a [`HistoryTable`] keeps its scores.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+enum HistoryScores<M: Hash + Eq> {
+    /// Indexed by [`Game::move_to_index`].
+    Indexed(Vec<u64>),
+    Hashed(HashMap<M, u64>),
+}
+
+/// The history heuristic: moves that caused cutoffs before are searched first.
+///
+/// Like [`WeightedMoveOrderer`], scores are looked up by move alone.
+/// If the game maps its moves to indices (see [`Game::move_to_index`]),
+/// scores are stored in a flat `Vec`; otherwise, they're stored in a `HashMap`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct HistoryTable<T: Game>
+where
+    T::Move: Hash + Eq,
+{
+    scores: HistoryScores<T::Move>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Game> HistoryTable<T>
+where
+    T::Move: Hash + Eq,
+{
+    /// Creates an empty history table for the moves of `game`.
+    pub fn new(game: &T) -> Self {
+        let scores = match game.move_to_index_count() {
+            Some(count) => HistoryScores::Indexed(vec![0; count]),
+            None => HistoryScores::Hashed(HashMap::new()),
+        };
+
+        Self { scores }
+    }
+
+    /// Records that `m` caused a cutoff in `game`, with `depth` moves left to search.
+    ///
+    /// Cutoffs further from the leaves weigh more, as they prune larger subtrees.
+    pub fn record_cutoff(&mut self, game: &T, m: &T::Move, depth: usize) {
+        let bonus = (depth as u64).saturating_mul(depth as u64);
+
+        match &mut self.scores {
+            HistoryScores::Indexed(scores) => {
+                if let Some(score) = game.move_to_index(m).and_then(|i| scores.get_mut(i)) {
+                    *score = score.saturating_add(bonus);
+                }
+            }
+            HistoryScores::Hashed(scores) => {
+                let score = scores.entry(m.clone()).or_default();
+                *score = score.saturating_add(bonus);
+            }
+        }
+    }
+
+    /// The history score of `m` in `game`, or 0 if it never caused a cutoff.
+    pub fn score(&self, game: &T, m: &T::Move) -> u64 {
+        match &self.scores {
+            HistoryScores::Indexed(scores) => game
+                .move_to_index(m)
+                .and_then(|i| scores.get(i))
+                .copied()
+                .unwrap_or_default(),
+            HistoryScores::Hashed(scores) => scores.get(m).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Forgets every recorded cutoff.
+    pub fn clear(&mut self) {
+        match &mut self.scores {
+            HistoryScores::Indexed(scores) => scores.fill(0),
+            HistoryScores::Hashed(scores) => scores.clear(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Game> MoveOrderer<T> for HistoryTable<T>
+where
+    T::Move: Hash + Eq,
+{
+    fn order_moves(&self, game: &T, moves: &mut [T::Move]) {
+        moves.sort_by_key(|m| core::cmp::Reverse(self.score(game, m)));
+    }
+}
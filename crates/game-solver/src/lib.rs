@@ -3,33 +3,77 @@
 //! If you want to read how to properly use this library,
 //! [the book](https://leodog896.github.io/game-solver/book) is
 //! a great place to start.
+//!
+//! Without the default `std` feature, this crate is `no_std` (though it still requires `alloc`).
+//! The `rayon`, `xxhash`, `rand`, and `bench` features, as well as anything relying
+//! on [`HashMap`](std::collections::HashMap), still require `std`.
+//!
+//! The `games` feature bundles games with known solutions (for now, Nim),
+//! as reference implementations of [`game::Game`].
+//!
+//! The `nightly` feature needs a nightly compiler, and enables hardware prefetching
+//! in [`transposition::prefetch_read`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics))]
+#![cfg_attr(feature = "nightly", allow(internal_features))]
 
+extern crate alloc;
+// lets the tests use `#[derive(Game)]`, which refers to this crate by name.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as game_solver;
+
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod cached;
+pub mod config;
+pub mod cooperative;
 pub mod disjoint_game;
 pub mod game;
+#[cfg(feature = "games")]
+pub mod games;
+pub mod heuristic;
+pub mod logged;
+pub mod loopy;
+pub mod maxn;
+pub mod notation;
 pub mod player;
+#[cfg(feature = "std")]
+pub mod pns;
+pub mod restricted;
+pub mod solver;
 pub mod stats;
-pub mod loopy;
+pub mod stochastic;
+#[cfg(feature = "std")]
+pub mod summary;
 // TODO: reinforcement
 // #[cfg(feature = "reinforcement")]
 // pub mod reinforcement;
 pub mod transposition;
+#[cfg(feature = "std")]
+pub mod tree;
+pub mod util;
 
+#[cfg(feature = "rayon")]
+use core::hash::BuildHasher;
 use core::panic;
+use core::sync::atomic::{AtomicBool, Ordering};
 #[cfg(feature = "rayon")]
 use tokio_util::sync::CancellationToken;
-#[cfg(feature = "rayon")]
-use std::hash::BuildHasher;
-#[cfg(feature = "rayon")]
-use std::sync::Arc;
-use std::sync::atomic::Ordering;
 
-use game::{upper_bound, GameState};
-use player::{ImpartialPlayer, TwoPlayer};
+use config::SolverConfig;
+use game::{max_score, GameState};
+use heuristic::MoveOrderer;
+use player::{ImpartialPlayer, Player, TwoPlayer};
+use smallvec::SmallVec;
 use stats::Stats;
 
 use crate::game::Game;
 use crate::transposition::{Score, TranspositionTable};
-use std::hash::Hash;
+use alloc::{borrow::Cow, boxed::Box, sync::Arc, vec, vec::Vec};
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet, VecDeque};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -38,106 +82,430 @@ pub enum GameSolveError<T: Game> {
     MoveError(T::MoveError),
     #[error("solving was cancelled")]
     Cancelled,
+    #[error("the game has no upper bound on its amount of moves, so it can't be scored")]
+    UnboundedGame,
+}
+
+/// The [`max_score`] of `game`, or [`GameSolveError::UnboundedGame`] if it has no move bound.
+pub(crate) fn bounded_max_score<T: Game>(game: &T) -> Result<isize, GameSolveError<T>> {
+    max_score(game).ok_or(GameSolveError::UnboundedGame)
+}
+
+/// The window `[alpha, beta)` that [`solve`] narrows down to the score of `game`,
+/// from [`Game::min_score`] and [`Game::max_score`] if the game has them,
+/// or from `max_score` otherwise.
+pub(crate) fn score_window<T: Game>(game: &T, max_score: isize) -> (isize, isize) {
+    (
+        game.min_score().unwrap_or(-max_score),
+        game.max_score().unwrap_or(max_score) + 1,
+    )
+}
+
+/// The moves of a position, as collected during search.
+///
+/// Most games have few enough moves to fit on the stack, which saves an allocation
+/// for every searched position.
+pub(crate) type Moves<T> = SmallVec<[<T as Game>::Move; 32]>;
+
+/// Collects the [`Game::canonical_moves`] of `game`, to be searched.
+pub(crate) fn collect_moves<T: Game>(game: &T) -> Moves<T> {
+    game.canonical_moves().collect()
+}
+
+/// Whether `game` is scored as a draw before its state is looked at,
+/// i.e. it repeats an earlier position or the game says it's drawn.
+fn is_drawn<T: Game>(game: &T) -> bool {
+    game.is_repeated_position() || game.is_draw() || game.is_repetition_draw()
+}
+
+/// Counts a win for `winning_player`, reached from `game`, in the terminal ends of `stats`.
+fn count_win<T: Game<Player = impl TwoPlayer + 'static>>(
+    stats: &Stats<T::Player>,
+    game: &T,
+    winning_player: T::Player,
+) {
+    let winning = if let Ok(player) = castaway::cast!(winning_player, ImpartialPlayer) {
+        ImpartialPlayer::from_move_count(stats.original_move_count, game.move_count()) == player
+    } else {
+        stats.original_player == winning_player
+    };
+
+    if winning {
+        stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
+    } else {
+        stats.terminal_ends.losing.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Options for a single [`negamax_raw`] search.
+pub struct NegamaxOptions<'a, P: Player> {
+    /// The lower end of the search window.
+    pub alpha: isize,
+    /// The upper end of the search window.
+    pub beta: isize,
+    /// The amount of moves to search before scoring positions as ties,
+    /// or None to search until the game ends.
+    pub depth: Option<usize>,
+    /// The score of a draw for the player to move in the searched position
+    /// (see [`SolverConfig::draw_value`]).
+    pub draw_value: isize,
+    /// Stops the search (with [`GameSolveError::Cancelled`]) once this is set.
+    pub cancelled: Option<Arc<AtomicBool>>,
+    pub stats: Option<&'a Stats<P>>,
+}
+
+impl<'a, P: Player> NegamaxOptions<'a, P> {
+    /// Searches the window `[alpha, beta]` until the game ends, with every other option unset.
+    pub fn new(alpha: isize, beta: isize) -> Self {
+        Self {
+            alpha,
+            beta,
+            depth: None,
+            draw_value: 0,
+            cancelled: None,
+            stats: None,
+        }
+    }
+
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn with_draw_value(mut self, draw_value: isize) -> Self {
+        self.draw_value = draw_value;
+        self
+    }
+
+    pub fn with_cancelled(mut self, cancelled: Arc<AtomicBool>) -> Self {
+        self.cancelled = Some(cancelled);
+        self
+    }
+
+    pub fn with_stats(mut self, stats: Option<&'a Stats<P>>) -> Self {
+        self.stats = stats;
+        self
+    }
 }
 
-/// Runs the two-player minimax variant on a zero-sum game.
-/// Since it uses alpha-beta pruning, you can specify an alpha beta window.
-fn negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+/// Runs the two-player minimax variant on a zero-sum game, with the window and options
+/// given by `options`, for when [`solve`] and its variants don't give enough control.
+///
+/// This is a hard-fail implementation: the returned score is always clamped to `[alpha, beta]`.
+/// Only scores inside the window are exact.
+///
+/// If a depth is given, positions past it are scored as 0 once they're quiet (see [`Game::is_quiet`]),
+/// and nothing derived from them is stored in the transposition table, so the result is only a guess.
+pub fn negamax_raw<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
     game: &T,
     transposition_table: &mut dyn TranspositionTable<T>,
-    mut alpha: isize,
-    mut beta: isize,
+    options: NegamaxOptions<'_, T::Player>,
+) -> Result<isize, GameSolveError<T>> {
+    let search_options = SearchOptions {
+        depth: options.depth,
+        draw_value: options.draw_value,
+        root_move_count: game.move_count(),
+        cancelled: options.cancelled.as_deref(),
+        ..SearchOptions::default()
+    };
+
+    negamax_inner(
+        game,
+        transposition_table,
+        options.alpha,
+        options.beta,
+        options.stats,
+        search_options,
+        &mut Tainted::default(),
+    )
+}
+
+/// Soft-fail variant of the internal negamax search.
+///
+/// Instead of clamping to `beta` on a cutoff (or to `alpha` when every move fails low),
+/// this returns the best score that was actually found. Scores that land inside the window
+/// are stored as [`Score::Exact`], and bounds outside of it are tighter than the window itself.
+///
+/// The tradeoff: a single call may explore more nodes than the hard-fail search,
+/// as it keeps track of scores past the window, but the tighter bounds it returns
+/// (and stores in the transposition table) reduce the total amount of nodes explored
+/// across the null-window probes in [`solve`].
+pub fn negamax_soft_fail<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    alpha: isize,
+    beta: isize,
     stats: Option<&Stats<T::Player>>,
 ) -> Result<isize, GameSolveError<T>> {
-    if let Some(stats) = stats {
-        stats.states_explored.fetch_add(1, Ordering::Relaxed);
+    let options = SearchOptions {
+        soft_fail: true,
+        ..SearchOptions::default()
+    };
+    negamax_inner(
+        game,
+        transposition_table,
+        alpha,
+        beta,
+        stats,
+        options,
+        &mut Tainted::default(),
+    )
+}
+
+/// Options that apply to every node of the internal negamax search.
+struct SearchOptions<'a, T: Game> {
+    /// Return the best score found instead of clamping it to the window.
+    soft_fail: bool,
+    /// The amount of moves left to search before giving up,
+    /// or None to search until the game ends.
+    depth: Option<usize>,
+    /// Reorders the moves of every position before searching them.
+    move_orderer: Option<&'a dyn MoveOrderer<T>>,
+    /// The score of a draw, for the player to move at the root (see [`SolverConfig::draw_value`]).
+    draw_value: isize,
+    /// The move count of the root, to know whose perspective `draw_value` is from.
+    root_move_count: usize,
+    /// Stops the search once set.
+    cancelled: Option<&'a AtomicBool>,
+}
+
+impl<T: Game> SearchOptions<'_, T> {
+    /// The score of a draw from the perspective of the player to move in `game`.
+    ///
+    /// A nonzero draw value is relative to the root, so scores derived from it are `tainted`.
+    fn draw_score(&self, game: &T, tainted: &mut Tainted) -> isize {
+        tainted.path_dependent |= self.draw_value != 0;
+
+        if game.move_count().abs_diff(self.root_move_count) % 2 == 0 {
+            self.draw_value
+        } else {
+            -self.draw_value
+        }
     }
+}
 
-    // TODO: debug-based depth counting
-    // if let Some(stats) = stats {
-    //     stats.max_depth.fetch_max(depth, Ordering::Relaxed);
-    // }
+// (derives would require `T: Clone + Copy`)
+impl<T: Game> Clone for SearchOptions<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
-    // TODO(perf): if find_immediately_resolvable_game satisfies its contract,
-    // we can ignore this at larger depths.
+impl<T: Game> Copy for SearchOptions<'_, T> {}
+
+impl<T: Game> Default for SearchOptions<'_, T> {
+    fn default() -> Self {
+        Self {
+            soft_fail: false,
+            depth: None,
+            move_orderer: None,
+            draw_value: 0,
+            root_move_count: 0,
+            cancelled: None,
+        }
+    }
+}
+
+/// What a search ran into that keeps the scores it found out of the transposition table,
+/// since they're not the scores of the positions themselves.
+#[derive(Clone, Copy, Default)]
+struct Tainted {
+    /// Positions past the depth limit were scored as ties.
+    truncated: bool,
+    /// Draws were scored relative to the root (with a nonzero draw value),
+    /// or the path taken to them (repetitions).
+    path_dependent: bool,
+}
+
+impl Tainted {
+    fn any(self) -> bool {
+        self.truncated || self.path_dependent
+    }
+}
+
+impl core::ops::BitOrAssign for Tainted {
+    fn bitor_assign(&mut self, other: Self) {
+        self.truncated |= other.truncated;
+        self.path_dependent |= other.path_dependent;
+    }
+}
+
+/// Searches `game` with the full window, scoring positions `depth` moves ahead as ties.
+#[cfg(feature = "std")]
+pub(crate) fn shallow_negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    depth: usize,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    let options = SearchOptions {
+        depth: Some(depth),
+        ..SearchOptions::default()
+    };
+
+    let max_score = bounded_max_score(game)?;
+
+    negamax_inner(
+        game,
+        transposition_table,
+        -max_score,
+        max_score,
+        stats,
+        options,
+        &mut Tainted::default(),
+    )
+}
+
+/// Scores `game` by its [`Game::state`] for the player to move, if the game is over,
+/// counting how it ended in `stats`.
+fn score_state<T: Game<Player = impl TwoPlayer + 'static>>(
+    game: &T,
+    max_score: isize,
+    stats: Option<&Stats<T::Player>>,
+    options: &SearchOptions<'_, T>,
+    tainted: &mut Tainted,
+) -> Option<isize> {
     match game.state() {
-        GameState::Playable => (),
+        GameState::Playable => None,
         GameState::Tie => {
             if let Some(stats) = stats {
                 stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
             }
-            return Ok(0);
+            Some(options.draw_score(game, tainted))
         }
         GameState::Win(winning_player) => {
-            // TODO: can we not duplicate this
             if let Some(stats) = stats {
-                if let Ok(player) = castaway::cast!(winning_player, ImpartialPlayer) {
-                    if ImpartialPlayer::from_move_count(
-                        stats.original_move_count,
-                        game.move_count(),
-                    ) == player
-                    {
-                        stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
-                    } else {
-                        stats.terminal_ends.losing.fetch_add(1, Ordering::Relaxed);
-                    }
-                } else if stats.original_player == winning_player {
-                    stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
-                } else {
-                    stats.terminal_ends.losing.fetch_add(1, Ordering::Relaxed);
-                }
+                count_win(stats, game, winning_player);
             }
 
             // if the next player is the winning player,
             // the score should be positive.
             if game.player() == winning_player {
-                // we add one to make sure games that use up every move
+                // `max_score` adds one to make sure games that use up every move
                 // aren't represented by ties.
                 //
                 // take the 2 heap game where each heap has one object in Nim, for example
                 // player 2 will always win since 2 moves will always be used,
                 // but since the upper bound is 2, 2 - 2 = 0,
                 // but we reserve 0 for ties.
-                return Ok(upper_bound(game) - game.move_count() as isize + 1);
+                Some(max_score)
             } else {
-                return Ok(-(upper_bound(game) - game.move_count() as isize + 1));
+                Some(-max_score)
             }
         }
-    };
+    }
+}
+
+/// The internal negamax search.
+///
+/// If a depth is given, positions past it are scored as 0 (once they're quiet, see [`Game::is_quiet`])
+/// and `tainted` is marked as truncated. Since those scores aren't real results, nothing derived from them
+/// is stored in the transposition table (and likewise for draws scored with a nonzero draw value).
+fn negamax_inner<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    mut alpha: isize,
+    mut beta: isize,
+    stats: Option<&Stats<T::Player>>,
+    options: SearchOptions<'_, T>,
+    tainted: &mut Tainted,
+) -> Result<isize, GameSolveError<T>> {
+    let soft_fail = options.soft_fail;
+
+    if let Some(stats) = stats {
+        stats.states_explored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    if options
+        .cancelled
+        .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    {
+        return Err(GameSolveError::Cancelled);
+    }
+
+    let max_score = bounded_max_score(game)?;
+
+    debug_assert!(
+        game.move_count() <= game.max_moves().unwrap_or(usize::MAX),
+        "move_count exceeded max_moves"
+    );
+
+    // repeated positions are draws - otherwise, loopy games would never terminate.
+    // whether a position repeats depends on how it was reached, which its score shouldn't.
+    if game.is_repeated_position() || game.is_repetition_draw() {
+        tainted.path_dependent = true;
+        if let Some(stats) = stats {
+            stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok(options.draw_score(game, tainted));
+    }
+
+    if game.is_draw() {
+        if let Some(stats) = stats {
+            stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok(options.draw_score(game, tainted));
+    }
+
+    // TODO: debug-based depth counting
+    // if let Some(stats) = stats {
+    //     stats.max_depth.fetch_max(depth, Ordering::Relaxed);
+    // }
+
+    // TODO(perf): if find_immediately_resolvable_game satisfies its contract,
+    // we can ignore this at larger depths.
+    if let Some(score) = score_state(game, max_score, stats, &options, tainted) {
+        return Ok(score);
+    }
+
+    // moves are only generated once, for both finding resolvable games and searching.
+    let mut moves = collect_moves(game);
+
+    // winning right away is the best we can do, and some games know a move wins without making it.
+    if let Some(score) = moves.iter().find_map(|m| {
+        game.score_after_winning_move(m)
+            .or_else(|| game.is_winning_move(m).then_some(max_score - 1))
+    }) {
+        if let Some(stats) = stats {
+            count_win(stats, game, game.player().turn());
+        }
+        return Ok(score);
+    }
+
+    // a game without any moves is over: without this, it would be scored
+    // as if every move had been searched and failed low, without any moves to search.
+    // a playable game without any moves leaves the player to move with nothing to do but lose.
+    if moves.is_empty() {
+        return Ok(
+            score_state(game, max_score, stats, &options, tainted).unwrap_or_else(|| {
+                if let Some(stats) = stats {
+                    count_win(stats, game, game.player().turn());
+                }
+                -max_score
+            }),
+        );
+    }
 
     // check if this is a winning configuration
-    if let Ok(Some(board)) = game.find_immediately_resolvable_game() {
+    if let Ok(Some(board)) = game.find_immediately_resolvable_game_among(&moves) {
         match board.state() {
             GameState::Playable => panic!("A resolvable game should not be playable."),
             GameState::Tie => {
                 if let Some(stats) = stats {
                     stats.terminal_ends.tie.fetch_add(1, Ordering::Relaxed);
                 }
-                return Ok(0);
+                return Ok(options.draw_score(game, tainted));
             }
             GameState::Win(winning_player) => {
                 if let Some(stats) = stats {
-                    if let Ok(player) = castaway::cast!(winning_player, ImpartialPlayer) {
-                        if ImpartialPlayer::from_move_count(
-                            stats.original_move_count,
-                            game.move_count(),
-                        ) == player
-                        {
-                            stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
-                        } else {
-                            stats.terminal_ends.losing.fetch_add(1, Ordering::Relaxed);
-                        }
-                    } else if stats.original_player == winning_player {
-                        stats.terminal_ends.winning.fetch_add(1, Ordering::Relaxed);
-                    } else {
-                        stats.terminal_ends.losing.fetch_add(1, Ordering::Relaxed);
-                    }
+                    count_win(stats, game, winning_player);
                 }
 
+                // the resolvable game is one move further, so it scores one less.
                 if game.player().turn() == winning_player {
-                    return Ok(upper_bound(&board) - board.move_count() as isize + 1);
+                    return Ok(max_score - 1);
                 } else {
-                    return Ok(-(upper_bound(&board) - board.move_count() as isize + 1));
+                    return Ok(-(max_score - 1));
                 }
             }
         }
@@ -147,7 +515,7 @@ fn negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
     {
         let score = transposition_table
             .get(game)
-            .unwrap_or_else(|| Score::UpperBound(upper_bound(game)));
+            .unwrap_or_else(|| Score::UpperBound(max_score + game.move_count() as isize - 1));
 
         match score {
             Score::UpperBound(max) => {
@@ -172,41 +540,128 @@ fn negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
                     }
                 }
             }
+            Score::Exact(score) => {
+                if let Some(stats) = stats {
+                    stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+                }
+                return Ok(if soft_fail {
+                    score
+                } else {
+                    score.clamp(alpha, beta)
+                });
+            }
         };
     }
 
-    // for [principal variation search](https://www.chessprogramming.org/Principal_Variation_Search)
-    let mut first_child = true;
+    // we've reached the search horizon, and don't know anything about this position
+    if options.depth == Some(0) {
+        tainted.truncated = true;
 
-    for m in &mut game.possible_moves() {
-        let mut board = game.clone();
-        board
-            .make_move(&m)
+        // noisy positions are settled first, with a [quiescence search](https://www.chessprogramming.org/Quiescence_Search)
+        // over their noisy moves. the player to move can still stop there, which keeps the tie.
+        let mut best = 0;
+        if best < beta && !game.is_quiet() {
+            let mut quiescence_alpha = alpha.max(best);
+
+            for m in game.noisy_moves() {
+                let board = game::play(Cow::Borrowed(game), &m)
+                    .map_err(|err| GameSolveError::MoveError::<T>(err))?;
+
+                // the depth stays at 0, so quiet positions past here are scored as ties again.
+                let score = -negamax_inner(
+                    &board,
+                    transposition_table,
+                    -beta,
+                    -quiescence_alpha,
+                    stats,
+                    options,
+                    tainted,
+                )?;
+
+                best = best.max(score);
+                if score >= beta {
+                    break;
+                }
+                quiescence_alpha = quiescence_alpha.max(score);
+            }
+        }
+
+        return Ok(if soft_fail {
+            best
+        } else {
+            best.clamp(alpha, beta)
+        });
+    }
+
+    let child_options = SearchOptions {
+        depth: options.depth.map(|depth| depth - 1),
+        ..options
+    };
+    let mut subtree_tainted = Tainted::default();
+
+    // the window we actually search with, after the transposition table narrowed it
+    let search_alpha = alpha;
+    let mut best = -(max_score + game.move_count() as isize - 1);
+
+    // for [principal variation search](https://www.chessprogramming.org/Principal_Variation_Search):
+    // moves are searched with the full window until one of them raises alpha,
+    // and only the moves after that best move so far are checked with null windows.
+    // (searching only the first move in full would break down when moves aren't ordered best first.)
+    let mut raised_alpha = false;
+
+    if let Some(move_orderer) = options.move_orderer {
+        move_orderer.order_moves(game, &mut moves);
+    }
+
+    for m in &moves {
+        debug_assert!(
+            !game.state().is_terminal(),
+            "negamax is exploring a terminal state"
+        );
+
+        // the position is still needed to store its score once every move is searched,
+        // so it's always borrowed here.
+        let board = game::play(Cow::Borrowed(game), m)
             .map_err(|err| GameSolveError::MoveError::<T>(err))?;
 
-        let score = if first_child {
-            -negamax(
+        // the board is looked up once its moves are generated, which gives the table time to load it.
+        transposition_table.prefetch(&board);
+
+        let score = if !raised_alpha {
+            -negamax_inner(
                 &board,
                 transposition_table,
                 -beta,
                 -alpha,
-                stats
+                stats,
+                child_options,
+                &mut subtree_tainted,
             )?
         } else {
-            let score = -negamax(
+            let score = -negamax_inner(
                 &board,
                 transposition_table,
                 -alpha - 1,
                 -alpha,
-                stats
+                stats,
+                child_options,
+                &mut subtree_tainted,
             )?;
-            if score > alpha {
-                -negamax(
+            // only re-search if the null window search failed high,
+            // but still landed inside of the full window.
+            // (if it landed past beta, we're going to prune this node anyway)
+            if score > alpha && score < beta {
+                if let Some(stats) = stats {
+                    stats.researches.fetch_add(1, Ordering::Relaxed);
+                }
+                -negamax_inner(
                     &board,
                     transposition_table,
                     -beta,
                     -alpha,
-                    stats
+                    stats,
+                    child_options,
+                    &mut subtree_tainted,
                 )?
             } else {
                 score
@@ -218,20 +673,76 @@ fn negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
             if let Some(stats) = stats {
                 stats.pruning_cutoffs.fetch_add(1, Ordering::Relaxed);
             }
-            transposition_table.insert(game.clone(), Score::LowerBound(score));
-            return Ok(beta);
+            *tainted |= subtree_tainted;
+            if !subtree_tainted.any() {
+                insert_score(
+                    transposition_table,
+                    game,
+                    Score::LowerBound(score),
+                    options.depth,
+                );
+            }
+            return Ok(if soft_fail { score } else { beta });
+        }
+
+        if score > best {
+            best = score;
         }
 
         if score > alpha {
             alpha = score;
+            raised_alpha = true;
         }
+    }
 
-        first_child = false;
+    *tainted |= subtree_tainted;
+
+    if !soft_fail {
+        if !subtree_tainted.any() {
+            insert_score(
+                transposition_table,
+                game,
+                Score::UpperBound(alpha),
+                options.depth,
+            );
+        }
+        return Ok(alpha);
     }
 
-    transposition_table.insert(game.clone(), Score::UpperBound(alpha));
+    if subtree_tainted.any() {
+        return Ok(best);
+    }
 
-    Ok(alpha)
+    // every move failed low: we only know that the score is at most `best`.
+    if best <= search_alpha {
+        insert_score(
+            transposition_table,
+            game,
+            Score::UpperBound(best),
+            options.depth,
+        );
+    } else {
+        insert_score(transposition_table, game, Score::Exact(best), options.depth);
+    }
+
+    Ok(best)
+}
+
+/// Stores the score of `game`, along with how deep it was searched if the search was depth-limited.
+fn insert_score<T: Game + Eq + Hash>(
+    transposition_table: &mut dyn TranspositionTable<T>,
+    game: &T,
+    score: Score,
+    depth: Option<usize>,
+) {
+    match depth {
+        Some(depth) => transposition_table.insert_with_depth(
+            game.clone(),
+            score,
+            depth.min(u8::MAX as usize) as u8,
+        ),
+        None => transposition_table.insert(game.clone(), score),
+    }
 }
 
 /// Solves a game, returning the evaluated score.
@@ -240,150 +751,1245 @@ fn negamax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
 /// In 2 player games, if a score > 0, then the player whose turn it is has a winning strategy.
 /// If a score < 0, then the player whose turn it is has a losing strategy.
 /// Else, the game is a draw (score = 0).
+///
+/// The score is found by narrowing down the range from [`Game::min_score`] to [`Game::max_score`],
+/// so games with tighter bounds are solved in fewer iterations.
 pub fn solve<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
     game: &T,
     transposition_table: &mut dyn TranspositionTable<T>,
-    stats: Option<&Stats<T::Player>>
+    stats: Option<&Stats<T::Player>>,
 ) -> Result<isize, GameSolveError<T>> {
-    let mut alpha = -upper_bound(game);
-    let mut beta = upper_bound(game) + 1;
+    solve_with_config(game, transposition_table, &SolverConfig::new(), stats)
+}
 
-    // we're trying to guess the score of the board via null windows
-    while alpha < beta {
-        let med = alpha + (beta - alpha) / 2;
+/// Solves a game like [`solve`], but returns right away if `transposition_table`
+/// already holds an exact score for `game`, as it does for every position [`solve`] solved.
+///
+/// This pays off when many positions transpose into ones that were already solved,
+/// such as the children scored by [`move_scores`].
+pub fn memoized_solve<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    if let Some(Score::Exact(score)) = transposition_table.get(game) {
+        if let Some(stats) = stats {
+            stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok(score);
+    }
 
-        // do a [null window search](https://www.chessprogramming.org/Null_Window)
-        let evaluation = negamax(
+    solve(game, transposition_table, stats)
+}
+
+/// Solves a game like [`solve`], with the search adjusted by `config`.
+pub fn solve_with_config<
+    T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash,
+    O: MoveOrderer<T>,
+>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    config: &SolverConfig<O>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    solve_with_cancelled(game, transposition_table, config, stats, None)
+}
+
+/// Solves a game like [`solve_with_config`], stopping with [`GameSolveError::Cancelled`]
+/// once `cancelled` is set.
+fn solve_with_cancelled<
+    T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash,
+    O: MoveOrderer<T>,
+>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    config: &SolverConfig<O>,
+    stats: Option<&Stats<T::Player>>,
+    cancelled: Option<&AtomicBool>,
+) -> Result<isize, GameSolveError<T>> {
+    // checked before searching, since a broken bound can leave nothing to search.
+    debug_assert!(
+        game.move_count() <= game.max_moves().unwrap_or(usize::MAX),
+        "move_count exceeded max_moves"
+    );
+
+    let options = SearchOptions {
+        soft_fail: config.soft_fail,
+        depth: None,
+        move_orderer: config
+            .move_orderer
+            .as_ref()
+            .map(|move_orderer| move_orderer as &dyn MoveOrderer<T>),
+        draw_value: config.draw_value,
+        root_move_count: game.move_count(),
+        cancelled,
+    };
+
+    let max_score = bounded_max_score(game)?;
+
+    // the shallowest forced win is as long as the loser can hold out, so it settles the score
+    // (see [`SolverConfig::forced_win_depth`]).
+    if let Some(max_depth) = config.forced_win_depth {
+        for depth in 1..=max_depth {
+            if find_forced_win(game, depth)?.is_some() {
+                let score = max_score - depth as isize;
+                insert_score(transposition_table, game, Score::Exact(score), None);
+                return Ok(score);
+            }
+        }
+    }
+
+    let mut tainted = Tainted::default();
+    let score = if let Some(initial_window) = config.aspiration_window_initial {
+        aspiration_search(
             game,
             transposition_table,
-            med,
-            med + 1,
-            stats
-        )?;
-
-        if evaluation <= med {
-            beta = evaluation;
-        } else {
-            alpha = evaluation;
+            initial_window,
+            stats,
+            options,
+            &mut tainted,
+        )?
+    } else {
+        let mut windows =
+            IterativeDeepeningIter::new(game, transposition_table, stats, options, max_score);
+        let mut score = windows.alpha;
+        for window in &mut windows {
+            (score, _) = window?;
         }
+        tainted = windows.tainted;
+        score
+    };
+
+    // the probes only store bounds, but their windows converged on the exact score,
+    // which lets later lookups (e.g. in [`memoized_solve`]) skip the search entirely.
+    if !tainted.any() {
+        insert_score(transposition_table, game, Score::Exact(score), None);
     }
 
-    Ok(alpha)
+    Ok(score)
 }
 
-/// Utility function to get a list of the move scores of a certain game.
-/// Since its evaluating the same game, you can use the same transposition table.
-///
-/// If you want to evaluate the score of a board as a whole, use the `solve` function.
-///
-/// # Returns
+/// Scores positions with several solvers at once, e.g. [`solve`] alongside heuristic searches,
+/// settling on the median of their scores.
 ///
-/// An iterator of tuples of the form `(move, score)`.
-pub fn move_scores<'a, T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
-    game: &'a T,
-    transposition_table: &'a mut dyn TranspositionTable<T>,
-    stats: Option<&'a Stats<T::Player>>
-) -> impl Iterator<Item = Result<(T::Move, isize), GameSolveError<T>>> + 'a {
-    game.possible_moves().map(move |m| {
-        let mut board = game.clone();
-        board
+/// Exact solvers always agree, but heuristic ones may not: positions where they disagree
+/// (see [`EnsembleSolver::solve_agreement`]) are the ones where exact analysis matters most.
+pub struct EnsembleSolver<T: Game> {
+    pub solvers: Vec<EnsembleMember<T>>,
+}
+
+/// A solver in an [`EnsembleSolver`].
+pub type EnsembleMember<T> = Box<dyn Fn(&T) -> isize>;
+
+impl<T: Game> Default for EnsembleSolver<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Game> EnsembleSolver<T> {
+    /// Creates an ensemble without any solvers.
+    pub fn new() -> Self {
+        Self {
+            solvers: Vec::new(),
+        }
+    }
+
+    pub fn with_solver(mut self, solver: impl Fn(&T) -> isize + 'static) -> Self {
+        self.solvers.push(Box::new(solver));
+        self
+    }
+
+    /// The median score of `game` over every solver, taking the lower one of the middle two
+    /// if there's an even amount of solvers.
+    ///
+    /// # Panics
+    ///
+    /// If the ensemble has no solvers.
+    pub fn solve(&self, game: &T) -> isize {
+        self.solve_agreement(game).0
+    }
+
+    /// The median score of `game` (see [`EnsembleSolver::solve`]),
+    /// alongside the fraction of solvers that gave that exact score.
+    ///
+    /// # Panics
+    ///
+    /// If the ensemble has no solvers.
+    pub fn solve_agreement(&self, game: &T) -> (isize, f64) {
+        assert!(
+            !self.solvers.is_empty(),
+            "an ensemble needs at least one solver"
+        );
+
+        let mut scores = self
+            .solvers
+            .iter()
+            .map(|solver| solver(game))
+            .collect::<Vec<_>>();
+        scores.sort_unstable();
+
+        let median = scores[(scores.len() - 1) / 2];
+        let agreeing = scores.iter().filter(|&&score| score == median).count();
+
+        (median, agreeing as f64 / scores.len() as f64)
+    }
+}
+
+/// The windows [`solve`] narrows down to the score of a position, one null window probe at a time.
+/// Made with [`iterative_deepen`].
+///
+/// Every probe yields the `(alpha, beta)` window it narrowed to, where the score is always
+/// in `alpha..=beta`: once `alpha == beta`, that's the score, and the iterator ends.
+/// The iterator also ends after yielding an error.
+pub struct IterativeDeepeningIter<'a, T: Game> {
+    game: &'a T,
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+    stats: Option<&'a Stats<T::Player>>,
+    options: SearchOptions<'a, T>,
+    alpha: isize,
+    beta: isize,
+    failed: bool,
+    tainted: Tainted,
+}
+
+impl<'a, T: Game> IterativeDeepeningIter<'a, T> {
+    fn new(
+        game: &'a T,
+        transposition_table: &'a mut dyn TranspositionTable<T>,
+        stats: Option<&'a Stats<T::Player>>,
+        options: SearchOptions<'a, T>,
+        max_score: isize,
+    ) -> Self {
+        let (alpha, beta) = score_window(game, max_score);
+
+        Self {
+            game,
+            transposition_table,
+            stats,
+            options,
+            alpha,
+            beta,
+            failed: false,
+            tainted: Tainted::default(),
+        }
+    }
+}
+
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash> Iterator
+    for IterativeDeepeningIter<'_, T>
+{
+    type Item = Result<(isize, isize), GameSolveError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.alpha >= self.beta {
+            return None;
+        }
+
+        // we're trying to guess the score of the board via null windows
+        // (we use i128 to avoid overflowing on games with no move bound)
+        let med = (self.alpha as i128 + (self.beta as i128 - self.alpha as i128) / 2) as isize;
+
+        // do a [null window search](https://www.chessprogramming.org/Null_Window)
+        let evaluation = negamax_inner(
+            self.game,
+            self.transposition_table,
+            med,
+            med + 1,
+            self.stats,
+            self.options,
+            &mut self.tainted,
+        );
+
+        match evaluation {
+            Ok(evaluation) if evaluation <= med => self.beta = evaluation,
+            Ok(evaluation) => self.alpha = evaluation,
+            Err(err) => {
+                self.failed = true;
+                return Some(Err(err));
+            }
+        }
+
+        Some(Ok((self.alpha, self.beta)))
+    }
+}
+
+/// Narrows down the score of `game` like [`solve`] does, yielding every window along the way,
+/// to watch the search progress or to stop it early (e.g. after a few probes, for a rough bound).
+///
+/// Running the iterator to its end solves the game: the last window is `(score, score)`.
+pub fn iterative_deepen<'a, T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &'a T,
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+) -> Result<IterativeDeepeningIter<'a, T>, GameSolveError<T>> {
+    let options = SearchOptions {
+        root_move_count: game.move_count(),
+        ..SearchOptions::default()
+    };
+
+    Ok(IterativeDeepeningIter::new(
+        game,
+        transposition_table,
+        None,
+        options,
+        bounded_max_score(game)?,
+    ))
+}
+
+/// Searches windows around a guess of 0, widening them whenever the score falls outside,
+/// until the score lands inside of one (see [`SolverConfig::aspiration_window_initial`]).
+fn aspiration_search<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    initial_window: isize,
+    stats: Option<&Stats<T::Player>>,
+    options: SearchOptions<'_, T>,
+    tainted: &mut Tainted,
+) -> Result<isize, GameSolveError<T>> {
+    // every score is strictly inside of these.
+    let max_score = bounded_max_score(game)?;
+    let (lowest, highest) = (-max_score - 1, max_score + 1);
+
+    let mut width = initial_window.max(2);
+    let mut alpha = (-width / 2).max(lowest);
+    let mut beta = (width / 2).max(alpha + 1).min(highest);
+
+    loop {
+        let evaluation = negamax_inner(
+            game,
+            transposition_table,
+            alpha,
+            beta,
+            stats,
+            options,
+            tainted,
+        )?;
+
+        if evaluation <= alpha && alpha > lowest {
+            // the score is at most `evaluation`.
+            beta = evaluation + 1;
+            alpha = evaluation.saturating_sub(width).max(lowest);
+        } else if evaluation >= beta && beta < highest {
+            // the score is at least `evaluation`.
+            alpha = evaluation - 1;
+            beta = evaluation.saturating_add(width).min(highest);
+        } else {
+            return Ok(evaluation);
+        }
+
+        width = width.saturating_mul(2);
+    }
+}
+
+/// Solves a game like [`solve`], with a single search over the full window of scores.
+///
+/// This skips the null window probes of [`solve`], which is usually faster for small games,
+/// where a single pass explores every position anyway. For larger games, the narrow probes
+/// of [`solve`] prune far more, so this may explore many more positions -
+/// benchmark both (e.g. with [`crate::stats::Stats`]) to see which suits your game.
+pub fn depth_first_solve<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, GameSolveError<T>> {
+    let max_score = bounded_max_score(game)?;
+
+    negamax_inner(
+        game,
+        transposition_table,
+        -max_score,
+        max_score,
+        stats,
+        SearchOptions::default(),
+        &mut Tainted::default(),
+    )
+}
+
+/// Finds a forced win for the player to move within `max_depth` moves (of both players),
+/// returning the winning line, or `None` if there's no such win.
+///
+/// Only wins the opponent can't avoid count: after each of the winner's moves,
+/// every reply has to lead to another forced win. The returned line follows the reply
+/// that holds out the longest. Since only forcing lines are explored, and the search gives up
+/// as soon as a reply escapes, this is much faster than [`solve`] on tactical positions
+/// with short wins, but it doesn't say anything about positions without one.
+/// [`solve_with_config`] can check for one before searching (see [`SolverConfig::forced_win_depth`]).
+pub fn find_forced_win<T: Game>(
+    game: &T,
+    max_depth: usize,
+) -> Result<Option<Vec<T::Move>>, GameSolveError<T>> {
+    if max_depth == 0 || game.state().is_terminal() || is_drawn(game) {
+        return Ok(None);
+    }
+
+    let winner = game.player().turn();
+
+    'moves: for m in game.possible_moves() {
+        let mut board = game.clone();
+        board.make_move(&m).map_err(GameSolveError::MoveError)?;
+
+        // drawn positions are scored as draws before anything else, like in [`negamax_inner`].
+        if is_drawn(&board) {
+            continue;
+        }
+
+        match board.state() {
+            GameState::Win(player) if player == winner => return Ok(Some(vec![m])),
+            GameState::Playable => (),
+            GameState::Win(_) | GameState::Tie => continue,
+        }
+
+        // a playable position without any replies is lost for the opponent.
+        if board.possible_moves().next().is_none() {
+            return Ok(Some(vec![m]));
+        }
+
+        // the opponent needs to reply, and then (unless the reply loses) we need to win again.
+        if max_depth < 2 {
+            continue;
+        }
+
+        let mut longest: Option<(T::Move, Vec<T::Move>)> = None;
+        for reply in board.possible_moves() {
+            let mut defended = board.clone();
+            defended
+                .make_move(&reply)
+                .map_err(GameSolveError::MoveError)?;
+
+            if is_drawn(&defended) {
+                continue 'moves;
+            }
+
+            let line = match defended.state() {
+                // the opponent's reply lost the game for them.
+                GameState::Win(player) if player == defended.player() => Vec::new(),
+                GameState::Playable => match find_forced_win(&defended, max_depth - 2)? {
+                    Some(line) => line,
+                    None => continue 'moves,
+                },
+                GameState::Win(_) | GameState::Tie => continue 'moves,
+            };
+
+            if longest
+                .as_ref()
+                .map_or(true, |(_, longest)| line.len() > longest.len())
+            {
+                longest = Some((reply, line));
+            }
+        }
+
+        let mut line = vec![m];
+        if let Some((reply, rest)) = longest {
+            line.push(reply);
+            line.extend(rest);
+        }
+
+        return Ok(Some(line));
+    }
+
+    Ok(None)
+}
+
+/// Runs minimax with alpha-beta pruning, where every score is kept from the perspective
+/// of one player (the maximizing player), instead of being negated between moves like negamax.
+///
+/// `is_maximizing` is true if the player to move in `game` is the maximizing player,
+/// and alternates with every move. Ended positions are scored by [`Game::evaluate`],
+/// so this suits games that aren't strictly zero-sum. With the default evaluation,
+/// this finds the same scores as [`solve`] (negated on the minimizing player's turns).
+///
+/// This is a soft-fail implementation: scores outside `[alpha, beta]` are bounds on the real score.
+/// Since scores depend on who's maximizing, transposition tables must only be shared between
+/// searches with the same maximizing player.
+pub fn minimax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    mut alpha: isize,
+    mut beta: isize,
+    is_maximizing: bool,
+) -> Result<isize, GameSolveError<T>> {
+    let max_score = bounded_max_score(game)?;
+    let maximizer = if is_maximizing {
+        game.player()
+    } else {
+        game.player().other()
+    };
+
+    if game.is_repeated_position() || game.is_repetition_draw() {
+        return Ok(0);
+    }
+
+    if game.is_draw() {
+        return Ok(game.evaluate(maximizer).unwrap_or(0));
+    }
+
+    match game.state() {
+        GameState::Playable => (),
+        GameState::Tie => return Ok(game.evaluate(maximizer).unwrap_or(0)),
+        GameState::Win(winning_player) => {
+            return Ok(game
+                .evaluate(maximizer)
+                .unwrap_or(if winning_player == maximizer {
+                    max_score
+                } else {
+                    -max_score
+                }));
+        }
+    }
+
+    match transposition_table.get(game) {
+        Some(Score::Exact(score)) => return Ok(score),
+        Some(Score::LowerBound(min)) => {
+            if min >= beta {
+                return Ok(min);
+            }
+            alpha = alpha.max(min);
+        }
+        Some(Score::UpperBound(max)) => {
+            if max <= alpha {
+                return Ok(max);
+            }
+            beta = beta.min(max);
+        }
+        None => (),
+    }
+
+    let (search_alpha, search_beta) = (alpha, beta);
+    let mut best = None;
+
+    for m in game.possible_moves() {
+        let mut board = game.clone();
+        board
+            .make_move(&m)
+            .map_err(|err| GameSolveError::MoveError::<T>(err))?;
+
+        let score = minimax(&board, transposition_table, alpha, beta, !is_maximizing)?;
+
+        if is_maximizing {
+            if best.map_or(true, |best| score > best) {
+                best = Some(score);
+            }
+            alpha = alpha.max(score);
+        } else {
+            if best.map_or(true, |best| score < best) {
+                best = Some(score);
+            }
+            beta = beta.min(score);
+        }
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    // a playable game without any moves is lost for the player to move (like in [`solve`]).
+    let Some(best) = best else {
+        return Ok(game.evaluate(maximizer).unwrap_or(if is_maximizing {
+            -max_score
+        } else {
+            max_score
+        }));
+    };
+
+    let score = if best <= search_alpha {
+        Score::UpperBound(best)
+    } else if best >= search_beta {
+        Score::LowerBound(best)
+    } else {
+        Score::Exact(best)
+    };
+    transposition_table.insert(game.clone(), score);
+
+    Ok(best)
+}
+
+/// Solves a game with [`minimax`], for the player to move in `game`.
+///
+/// Unlike [`solve`], this searches the full range of scores at once,
+/// since [`Game::evaluate`] may score positions outside of [`Game::min_score`]..=[`Game::max_score`].
+pub fn solve_minimax<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+) -> Result<isize, GameSolveError<T>> {
+    minimax(game, transposition_table, isize::MIN, isize::MAX, true)
+}
+
+/// Utility function to get a list of the move scores of a certain game.
+/// Since its evaluating the same game, you can use the same transposition table.
+///
+/// If you want to evaluate the score of a board as a whole, use the `solve` function.
+///
+/// # Returns
+///
+/// An iterator of tuples of the form `(move, score)`.
+pub fn move_scores<'a, T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &'a T,
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+    stats: Option<&'a Stats<T::Player>>,
+) -> impl Iterator<Item = Result<(T::Move, isize), GameSolveError<T>>> + 'a {
+    game.possible_moves().map(move |m| {
+        debug_assert!(
+            !game.state().is_terminal(),
+            "move_scores is exploring a terminal state"
+        );
+
+        let mut board = game.clone();
+        board
             .make_move(&m)
             .map_err(|err| GameSolveError::MoveError(err))?;
         // We flip the sign of the score because we want the score from the
         // perspective of the player playing the move, not the player whose turn it is.
-        Ok((
-            m,
-            -solve(&board, transposition_table, stats)?,
-        ))
+        Ok((m, -memoized_solve(&board, transposition_table, stats)?))
     })
 }
 
 pub type CollectedMoves<T> = Vec<Result<(<T as Game>::Move, isize), GameSolveError<T>>>;
 
-/// Parallelized version of `move_scores`. (faster by a large margin)
-/// This requires the `rayon` feature to be enabled.
-/// It uses rayon's parallel iterators to evaluate the scores of each move in parallel.
-///
-/// This also allows you to pass in your own hasher, for transposition table optimization.
+/// Sorts move scores from best to worst, keeping the original move order for equal scores.
+fn rank_move_scores<T: Game>(
+    move_scores: impl IntoIterator<Item = Result<(T::Move, isize), GameSolveError<T>>>,
+) -> Result<Vec<(T::Move, isize)>, GameSolveError<T>> {
+    let mut move_scores = move_scores.into_iter().collect::<Result<Vec<_>, _>>()?;
+    move_scores.sort_by(|a, b| b.1.cmp(&a.1));
+    Ok(move_scores)
+}
+
+/// Utility function to get the scores of every move of a game, sorted from best to worst.
 ///
 /// # Returns
 ///
-/// A vector of tuples of the form `(move, score)`.
-#[cfg(feature = "rayon")]
-pub async fn par_move_scores_with_hasher<
-    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
-    S,
->(
+/// A vector of tuples of the form `(move, score)`, where the best move comes first.
+pub fn move_scores_ranked<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
     game: &T,
-    stats: Option<Arc<Stats<T::Player>>>,
-    cancellation_token: Option<CancellationToken>
-) -> CollectedMoves<T>
-where
-    T::Move: Sync + Send,
-    T::MoveError: Sync + Send,
-    S: BuildHasher + Default + Sync + Send + Clone + 'static,
-{
-    use itertools::Itertools;
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<Vec<(T::Move, isize)>, GameSolveError<T>> {
+    rank_move_scores(move_scores(game, transposition_table, stats))
+}
 
-    use crate::transposition::TranspositionCache;
-    use std::sync::Arc;
+/// Utility function to get the `n` best moves of a game, sorted from best to worst.
+///
+/// Note that every move still has to be solved to know which moves are the best.
+pub fn best_n_moves<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    n: usize,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<Vec<(T::Move, isize)>, GameSolveError<T>> {
+    let mut move_scores = move_scores_ranked(game, transposition_table, stats)?;
+    move_scores.truncate(n);
+    Ok(move_scores)
+}
 
-    let result = game.possible_moves().map(|m| {
-        let m = m.clone();
-        let game = game.clone();
-        let cancellation_token = cancellation_token.clone();
-        let stats = stats.clone();
-        
-        tokio::spawn(async move {
-            let hashmap = Arc::new(TranspositionCache::<T, S>::new());
-            let mut board = game.clone();
-            board
-                .make_move(&m)
-                .map_err(|err| GameSolveError::MoveError::<T>(err))?;
-            // We flip the sign of the score because we want the score from the
-            // perspective of the player pla`ying the move, not the player whose turn it is.
-            let mut map = Arc::clone(&hashmap);
+/// A move that [`RankedMoveIter`] hasn't yielded yet,
+/// alongside the bounds of its score that have been proven so far.
+struct RankedCandidate<T: Game> {
+    m: T::Move,
+    board: T,
+    lower: isize,
+    upper: isize,
+}
 
-            let handle = tokio::spawn(async move {
-                solve(&board, &mut map, stats.as_deref()).map(|score| -score)
-            });
+/// An iterator over the moves of a game, from best to worst, that only evaluates
+/// moves as far as needed to know which one comes next.
+///
+/// Every move starts off with the score bounds found in the transposition table.
+/// The most promising move is solved exactly, and every other move
+/// is only checked (with a null window search) to be no better than it.
+///
+/// Created by [`ranked_moves`].
+pub struct RankedMoveIter<'a, T: Game> {
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+    stats: Option<&'a Stats<T::Player>>,
+    candidates: Vec<RankedCandidate<T>>,
+}
 
-            if let Some(cancellation_token) = cancellation_token {
-                tokio::select! {
-                    _ = cancellation_token.cancelled() => {
-                        Err(GameSolveError::Cancelled)
-                    },
-                    result = handle => {
-                        result.unwrap().map(|result| (m, result))
-                    }
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash> RankedMoveIter<'_, T> {
+    /// Tightens the bounds of the candidate at `index`.
+    ///
+    /// If `best` (the score of the best solved move) is known, this only checks
+    /// if the candidate can beat it, and otherwise, finds the candidate's actual score.
+    fn refine(&mut self, index: usize, best: Option<isize>) -> Result<(), GameSolveError<T>> {
+        let candidate = &mut self.candidates[index];
+
+        match best {
+            Some(best) if candidate.lower <= best => {
+                // test if the score beats `best` - since the score of the board is
+                // from the opponent's perspective, we flip the window.
+                let evaluation = negamax_soft_fail(
+                    &candidate.board,
+                    self.transposition_table,
+                    -best - 1,
+                    -best,
+                    self.stats,
+                )?;
+
+                if evaluation < -best {
+                    candidate.lower = candidate.lower.max(-evaluation);
+                } else {
+                    candidate.upper = candidate.upper.min(-evaluation);
                 }
-            } else {
-                handle.await.unwrap().map(|x| (m, x))
             }
-        })
-    })
-    .collect::<Vec<_>>();
+            _ => {
+                let score = -solve(&candidate.board, self.transposition_table, self.stats)?;
+                candidate.lower = score;
+                candidate.upper = score;
+            }
+        }
 
-    futures::future::join_all(result).await.into_iter().map(|result| result.unwrap()).collect_vec()
+        Ok(())
+    }
 }
 
-/// Parallelized version of `move_scores`. (faster by a large margin)
-/// This requires the `rayon` feature to be enabled.
-/// It uses rayon's parallel iterators to evaluate the scores of each move in parallel.
-///
-/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher.
-/// If you want to use your own hasher, use [`par_move_scores_with_hasher`].
-///
-/// # Returns
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash> Iterator for RankedMoveIter<'_, T> {
+    type Item = Result<(T::Move, isize), GameSolveError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_exact = |candidate: &RankedCandidate<T>| candidate.lower == candidate.upper;
+
+        loop {
+            // the most promising move, preferring ones that are already solved
+            let index = (0..self.candidates.len()).reduce(|best, index| {
+                let (best_candidate, candidate) = (&self.candidates[best], &self.candidates[index]);
+
+                if (candidate.upper, is_exact(candidate))
+                    > (best_candidate.upper, is_exact(best_candidate))
+                {
+                    index
+                } else {
+                    best
+                }
+            })?;
+
+            // nothing else can beat a solved move with the highest upper bound
+            if is_exact(&self.candidates[index]) {
+                let candidate = self.candidates.remove(index);
+                return Some(Ok((candidate.m, candidate.lower)));
+            }
+
+            let best = self
+                .candidates
+                .iter()
+                .filter(|candidate| is_exact(candidate))
+                .map(|candidate| candidate.lower)
+                .max();
+
+            if let Err(err) = self.refine(index, best) {
+                self.candidates.clear();
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+/// Lazily ranks the moves of a game, yielding `(move, score)` tuples by decreasing score.
 ///
-/// A vector of tuples of the form `(move, score)`.
-#[cfg(feature = "rayon")]
+/// Unlike [`move_scores_ranked`], this doesn't solve every move up front:
+/// if you only need the best move, the rest of the moves are only evaluated
+/// as far as needed to prove that they're worse.
+pub fn ranked_moves<'a, T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+    stats: Option<&'a Stats<T::Player>>,
+) -> Result<RankedMoveIter<'a, T>, GameSolveError<T>> {
+    let candidates = game
+        .possible_moves()
+        .map(|m| {
+            let mut board = game.clone();
+            board
+                .make_move(&m)
+                .map_err(|err| GameSolveError::MoveError(err))?;
+
+            // the score bounds of the board are from the opponent's perspective
+            let max_score = bounded_max_score(&board)?;
+            let (mut lower, mut upper) = (-max_score, max_score);
+            match transposition_table.get(&board) {
+                Some(Score::UpperBound(max)) => lower = lower.max(-max),
+                Some(Score::LowerBound(min)) => upper = upper.min(-min),
+                Some(Score::Exact(score)) => (lower, upper) = (-score, -score),
+                None => (),
+            }
+
+            Ok(RankedCandidate {
+                m,
+                board,
+                lower,
+                upper,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(RankedMoveIter {
+        transposition_table,
+        stats,
+        candidates,
+    })
+}
+
+/// `(depth, best move, score)` for every depth searched by [`opening_moves`].
+pub type OpeningMoves<T> = Vec<(usize, <T as Game>::Move, isize)>;
+
+/// Finds the best move at every depth of an iterative deepening search,
+/// up to `max_depth` moves ahead.
+///
+/// Positions past the current depth are scored as ties, so shallow results are only estimates.
+/// Searching stops early once the result can't change at a deeper depth:
+/// either a forced win or loss was found, or the whole game tree fit within the depth.
+///
+/// This is useful for seeding an opening book before a full solve finishes.
+///
+/// # Returns
+///
+/// A list of `(depth, best move, score)` tuples, one per completed depth.
+/// This is empty if the game has no moves.
+pub fn opening_moves<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    max_depth: usize,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<OpeningMoves<T>, GameSolveError<T>> {
+    let max_score = bounded_max_score(game)?;
+    let mut opening_moves = Vec::new();
+
+    for depth in 1..=max_depth {
+        let options = SearchOptions {
+            depth: Some(depth - 1),
+            ..SearchOptions::default()
+        };
+        let mut tainted = Tainted::default();
+        let mut best: Option<(T::Move, isize)> = None;
+
+        for m in game.possible_moves() {
+            let mut board = game.clone();
+            board
+                .make_move(&m)
+                .map_err(|err| GameSolveError::MoveError(err))?;
+
+            // only look for moves better than the best one so far
+            let alpha = best.as_ref().map_or(-max_score - 1, |(_, score)| *score);
+            let score = -negamax_inner(
+                &board,
+                transposition_table,
+                -(max_score + 1),
+                -alpha,
+                stats,
+                options,
+                &mut tainted,
+            )?;
+
+            if score > alpha {
+                best = Some((m, score));
+            }
+        }
+
+        let Some((best_move, score)) = best else {
+            break;
+        };
+
+        opening_moves.push((depth, best_move, score));
+
+        if !tainted.truncated || score != 0 {
+            break;
+        }
+    }
+
+    Ok(opening_moves)
+}
+
+/// Every position reachable from `initial` (including itself), in breadth-first order.
+#[cfg(feature = "std")]
+fn reachable_positions<T: Game + Eq + Hash>(initial: &T) -> Result<Vec<T>, GameSolveError<T>> {
+    let mut seen = HashSet::from([initial.clone()]);
+    let mut queue = VecDeque::from([initial.clone()]);
+    let mut positions = Vec::new();
+
+    while let Some(game) = queue.pop_front() {
+        for m in game.possible_moves() {
+            let mut board = game.clone();
+            board
+                .make_move(&m)
+                .map_err(|err| GameSolveError::MoveError(err))?;
+
+            if seen.insert(board.clone()) {
+                queue.push_back(board);
+            }
+        }
+
+        positions.push(game);
+    }
+
+    Ok(positions)
+}
+
+/// Solves every position reachable from `initial`, returning the score of each one.
+///
+/// Positions are found via breadth-first search, and positions whose exact score
+/// is already in the transposition table aren't solved again.
+///
+/// **Warning**: this holds every reachable position in memory,
+/// so it's only practical for games with fewer than ~10^7 positions.
+#[cfg(feature = "std")]
+pub fn solve_all_positions<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    initial: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<HashMap<T, isize>, GameSolveError<T>> {
+    reachable_positions(initial)?
+        .into_iter()
+        .map(|game| {
+            let score = match transposition_table.get(&game) {
+                Some(Score::Exact(score)) => score,
+                _ => solve(&game, transposition_table, stats)?,
+            };
+
+            Ok((game, score))
+        })
+        .collect()
+}
+
+/// Parallelized version of `move_scores`. (faster by a large margin)
+/// This requires the `rayon` feature to be enabled.
+/// It uses rayon's parallel iterators to evaluate the scores of each move in parallel,
+/// where every move shares the same concurrent transposition table.
+///
+/// This also allows you to pass in your own hasher, for transposition table optimization.
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`.
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_with_hasher<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    use crate::transposition::TranspositionCache;
+
+    // every move shares the same table, since sibling subtrees commonly transpose into each other.
+    let shared_tt = Arc::new(TranspositionCache::<T, S>::new());
+
+    par_move_scores_with_shared_tt(game, shared_tt, stats, cancellation_token).await
+}
+
+/// Like [`par_move_scores`], hashing positions with [`XxHash64`](twox_hash::XxHash64)
+/// seeded by `seed`, to reproduce a run exactly.
+/// This requires the `rayon` feature to be enabled.
+///
+/// Colliding hashes can't make scores wrong, since the transposition table
+/// still compares positions, but positions crafted to collide under a known seed
+/// can slow the table down a lot. [`par_move_scores`] picks a random seed on every call,
+/// so only pass a fixed seed for reproducibility, not for untrusted positions.
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`.
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_with_seed<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    game: &T,
+    seed: u64,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    use crate::transposition::{SeededXxHashBuilder64, TranspositionCache};
+
+    let shared_tt = Arc::new(TranspositionCache::<T, _>::with_hasher(
+        SeededXxHashBuilder64(seed),
+    ));
+
+    par_move_scores_with_shared_tt(game, shared_tt, stats, cancellation_token).await
+}
+
+/// Parallelized version of `move_scores`, where every move is solved against `shared_tt`.
+/// This requires the `rayon` feature to be enabled.
+///
+/// Unlike [`par_move_scores_with_hasher`], the caller owns the table,
+/// so it can be sized for the game, inspected afterwards,
+/// or reused across calls (e.g. for every position of a played-out game).
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`.
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_with_shared_tt<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    shared_tt: Arc<crate::transposition::TranspositionCache<T, S>>,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    use itertools::Itertools;
+
+    // collecting preallocates from the size hint of the moves, which is exact for `ExactGame`s.
+    let result = game
+        .possible_moves()
+        .map(|m| spawn_move_score(game, m, &shared_tt, &stats, &cancellation_token))
+        .collect::<Vec<_>>();
+
+    futures::future::join_all(result)
+        .await
+        .into_iter()
+        .map(|result| result.unwrap())
+        .collect_vec()
+}
+
+/// Like [`par_move_scores`], calling `on_move_done` with every move and its score
+/// as soon as that move is solved, e.g. to drive a progress bar.
+/// This requires the `rayon` feature to be enabled.
+///
+/// Moves are solved in parallel, but `on_move_done` is only ever called from the task
+/// awaiting this function, so calls never overlap. Moves that fail to solve
+/// (or are cancelled) don't call `on_move_done`.
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`, in the same order as [`par_move_scores`].
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_with_progress<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    F,
+>(
+    game: &T,
+    on_move_done: F,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    F: Fn(T::Move, isize) + Sync + Send,
+{
+    use crate::transposition::TranspositionCache;
+    use twox_hash::RandomXxHashBuilder64;
+
+    let shared_tt = Arc::new(TranspositionCache::<T, RandomXxHashBuilder64>::new());
+    let on_move_done = &on_move_done;
+
+    let result = game
+        .possible_moves()
+        .map(|m| {
+            let handle = spawn_move_score(game, m, &shared_tt, &stats, &cancellation_token);
+            async move {
+                let result = handle.await.unwrap();
+                if let Ok((m, score)) = &result {
+                    on_move_done(m.clone(), *score);
+                }
+                result
+            }
+        })
+        .collect::<Vec<_>>();
+
+    futures::future::join_all(result).await
+}
+
+#[cfg(feature = "rayon")]
+type MoveScore<T> = Result<(<T as Game>::Move, isize), GameSolveError<T>>;
+
+/// Spawns a task solving the position after `m`, scored for the player making `m`.
+#[cfg(feature = "rayon")]
+fn spawn_move_score<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    m: T::Move,
+    shared_tt: &Arc<crate::transposition::TranspositionCache<T, S>>,
+    stats: &Option<Arc<Stats<T::Player>>>,
+    cancellation_token: &Option<CancellationToken>,
+) -> tokio::task::JoinHandle<MoveScore<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    let game = game.clone();
+    let cancellation_token = cancellation_token.clone();
+    let stats = stats.clone();
+    let mut map = Arc::clone(shared_tt);
+
+    tokio::spawn(async move {
+        let board =
+            game::play(Cow::Owned(game), &m).map_err(|err| GameSolveError::MoveError::<T>(err))?;
+
+        // the solve runs on a task of its own, which aborting this one doesn't stop.
+        // instead, once this task is dropped (whether it's aborted, cancelled or done),
+        // the guard marks the solve as abandoned, which stops it.
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let _guard = AbandonOnDrop(Arc::clone(&abandoned));
+
+        // We flip the sign of the score because we want the score from the
+        // perspective of the player playing the move, not the player whose turn it is.
+        let handle = tokio::spawn(async move {
+            solve_with_cancelled(
+                &board,
+                &mut map,
+                &SolverConfig::new(),
+                stats.as_deref(),
+                Some(&abandoned),
+            )
+            .map(|score| -score)
+        });
+
+        if let Some(cancellation_token) = cancellation_token {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    Err(GameSolveError::Cancelled)
+                },
+                result = handle => {
+                    result.unwrap().map(|result| (m, result))
+                }
+            }
+        } else {
+            handle.await.unwrap().map(|x| (m, x))
+        }
+    })
+}
+
+/// Sets its flag once dropped, e.g. when the task holding it is aborted.
+#[cfg(feature = "rayon")]
+struct AbandonOnDrop(Arc<AtomicBool>);
+
+#[cfg(feature = "rayon")]
+impl Drop for AbandonOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Parallelized version of `move_scores` that stops as soon as a winning move is found.
+/// This requires the `rayon` feature to be enabled.
+///
+/// A move that wins right away scores `max_score(game) - 1`, which no other move can beat,
+/// so once one is found, the moves still being solved are stopped,
+/// and only the moves solved so far (including the winning move) are returned.
+/// Without such a move, every move is solved, as in [`par_move_scores_with_hasher`].
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`, in the order they were solved,
+/// with the winning move (if any) last.
+#[cfg(feature = "rayon")]
+pub async fn move_scores_lazy_parallel_with_hasher<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    use futures::StreamExt;
+
+    use crate::transposition::TranspositionCache;
+
+    let shared_tt = Arc::new(TranspositionCache::<T, S>::new());
+    // (games without a bound can't be solved, so no move wins for them)
+    let best_score = max_score(game).map_or(isize::MAX, |score| score - 1);
+
+    let handles = game
+        .possible_moves()
+        .map(|m| spawn_move_score(game, m, &shared_tt, &stats, &cancellation_token))
+        .collect::<Vec<_>>();
+    let abort_handles = handles
+        .iter()
+        .map(|handle| handle.abort_handle())
+        .collect::<Vec<_>>();
+
+    let mut pending = handles
+        .into_iter()
+        .collect::<futures::stream::FuturesUnordered<_>>();
+    let mut results = Vec::new();
+
+    while let Some(result) = pending.next().await {
+        let result = result.unwrap();
+        let is_winning = matches!(result, Ok((_, score)) if score >= best_score);
+        results.push(result);
+
+        if is_winning {
+            // moves that haven't started yet are never solved, and the ones being solved are stopped.
+            for handle in &abort_handles {
+                handle.abort();
+            }
+            break;
+        }
+    }
+
+    results
+}
+
+/// Parallelized version of `move_scores` that stops as soon as a winning move is found.
+/// See [`move_scores_lazy_parallel_with_hasher`] for details.
+///
+/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher.
+#[cfg(feature = "rayon")]
+pub async fn move_scores_lazy_parallel<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    if cfg!(feature = "xxhash") {
+        use twox_hash::RandomXxHashBuilder64;
+        move_scores_lazy_parallel_with_hasher::<T, RandomXxHashBuilder64>(
+            game,
+            stats,
+            cancellation_token,
+        )
+        .await
+    } else {
+        use std::collections::hash_map::RandomState;
+        move_scores_lazy_parallel_with_hasher::<T, RandomState>(game, stats, cancellation_token)
+            .await
+    }
+}
+
+/// Parallelized version of `move_scores`. (faster by a large margin)
+/// This requires the `rayon` feature to be enabled.
+/// It uses rayon's parallel iterators to evaluate the scores of each move in parallel.
+///
+/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher, with a random seed.
+/// If you want to use your own hasher, use [`par_move_scores_with_hasher`],
+/// or [`par_move_scores_with_seed`] to pick the seed.
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`.
+#[cfg(feature = "rayon")]
 pub async fn par_move_scores<
     T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
 >(
     game: &T,
     stats: Option<Arc<Stats<T::Player>>>,
-    cancellation_token: Option<CancellationToken>
+    cancellation_token: Option<CancellationToken>,
 ) -> CollectedMoves<T>
 where
     T::Move: Sync + Send,
@@ -391,9 +1997,1931 @@ where
 {
     if cfg!(feature = "xxhash") {
         use twox_hash::RandomXxHashBuilder64;
-        par_move_scores_with_hasher::<T, RandomXxHashBuilder64>(game, stats, cancellation_token).await
+        par_move_scores_with_hasher::<T, RandomXxHashBuilder64>(game, stats, cancellation_token)
+            .await
     } else {
         use std::collections::hash_map::RandomState;
         par_move_scores_with_hasher::<T, RandomState>(game, stats, cancellation_token).await
     }
 }
+
+/// Parallelized version of `move_scores_ranked`.
+/// This requires the `rayon` feature to be enabled.
+///
+/// # Returns
+///
+/// A vector of tuples of the form `(move, score)`, where the best move comes first.
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_ranked<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<Vec<(T::Move, isize)>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    rank_move_scores(par_move_scores(game, stats, cancellation_token).await)
+}
+
+/// Parallelized version of `solve`.
+/// This requires the `rayon` feature to be enabled.
+///
+/// Instead of probing a single null window at a time, this spreads
+/// one null window probe per available thread across the remaining `[alpha, beta)` window,
+/// and narrows the window with every probe's result as soon as it finishes.
+/// Once a result settles the score, it's returned right away, and the other probes are stopped
+/// (as are probes that the narrowed window left behind, before the next ones are spread out).
+///
+/// Every probe shares the same concurrent transposition table.
+/// Since probes only ever store valid bounds, concurrent writes can't contradict
+/// each other - at worst, a probe overwrites a tighter bound with a looser one.
+///
+/// This also allows you to pass in your own hasher, for transposition table optimization.
+#[cfg(feature = "rayon")]
+pub async fn par_solve_with_hasher<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<isize, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    use itertools::Itertools;
+
+    use crate::transposition::TranspositionCache;
+
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get()) as isize;
+    let hashmap = Arc::new(TranspositionCache::<T, S>::new());
+
+    let (mut alpha, mut beta) = score_window(game, bounded_max_score(game)?);
+
+    while alpha < beta {
+        // spread the probes evenly over [alpha, beta).
+        // (we use i128 to avoid overflowing on games with no move bound)
+        let probes = threads.min(beta.saturating_sub(alpha));
+        let meds = (1..=probes)
+            .map(|i| {
+                let width = beta as i128 - alpha as i128;
+                (alpha as i128 + width * i as i128 / (probes as i128 + 1)) as isize
+            })
+            .dedup()
+            .collect_vec();
+
+        // probes that are still running are stopped once their result can't matter anymore.
+        let abandoned = Arc::new(AtomicBool::new(false));
+        let mut pending = meds.clone();
+        let mut probes = tokio::task::JoinSet::new();
+
+        for med in meds {
+            let game = game.clone();
+            let stats = stats.clone();
+            let mut map = Arc::clone(&hashmap);
+            let abandoned = Arc::clone(&abandoned);
+
+            // do a [null window search](https://www.chessprogramming.org/Null_Window)
+            probes.spawn(async move {
+                let options = NegamaxOptions::new(med, med + 1)
+                    .with_stats(stats.as_deref())
+                    .with_cancelled(abandoned);
+                (med, negamax_raw(&game, &mut map, options))
+            });
+        }
+
+        // results are used as soon as they come in, rather than once every probe is done.
+        loop {
+            let probe = if let Some(cancellation_token) = &cancellation_token {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => {
+                        abandoned.store(true, Ordering::Relaxed);
+                        return Err(GameSolveError::Cancelled);
+                    },
+                    probe = probes.join_next() => probe
+                }
+            } else {
+                probes.join_next().await
+            };
+
+            let Some(probe) = probe else {
+                break;
+            };
+            let (med, evaluation) = probe.unwrap();
+            let evaluation = evaluation?;
+            pending.retain(|&pending| pending != med);
+
+            if evaluation <= med {
+                beta = beta.min(evaluation);
+            } else {
+                alpha = alpha.max(evaluation);
+            }
+
+            // this result settled the score, or every probe left is outside of the window.
+            if alpha >= beta || pending.iter().all(|&med| med < alpha || med >= beta) {
+                abandoned.store(true, Ordering::Relaxed);
+                break;
+            }
+        }
+    }
+
+    Ok(alpha)
+}
+
+/// Solves `game` within the window `[alpha, beta]` with the
+/// [Young Brothers Wait Concept](https://www.chessprogramming.org/Young_Brothers_Wait_Concept).
+/// This requires the `rayon` feature to be enabled.
+///
+/// The first move of every position (see [`Game::split_moves`]) is searched on its own,
+/// and only once its score narrows the window are the remaining moves (its "younger brothers")
+/// searched in parallel. Naively searching every move in parallel would search them all
+/// with the full window, since no move has a score to prune the others with yet.
+///
+/// Positions are only split along the first move of every position (the principal variation,
+/// if moves are ordered best first); the younger brothers are each searched sequentially,
+/// all sharing `shared_tt`.
+///
+/// Like [`negamax_raw`], this is hard-fail: the returned score is clamped to `[alpha, beta]`.
+#[cfg(feature = "rayon")]
+pub async fn ybwc_solve<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: &T,
+    shared_tt: Arc<crate::transposition::TranspositionCache<T, S>>,
+    alpha: isize,
+    beta: isize,
+    stats: Option<Arc<Stats<T::Player>>>,
+) -> Result<isize, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    // scores outside of the game's own bounds are never found, so they aren't searched
+    // (like in [`solve`]), but the result is still clamped to the window that was asked for.
+    let alpha = game
+        .min_score()
+        .map_or(alpha, |min| alpha.max(min).min(beta));
+    let beta = game
+        .max_score()
+        .map_or(beta, |max| beta.min(max).max(alpha));
+
+    ybwc_search(game.clone(), shared_tt, alpha, beta, stats).await
+}
+
+/// The search behind [`ybwc_solve`], boxed since it recurses into the first move.
+#[cfg(feature = "rayon")]
+fn ybwc_search<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    game: T,
+    shared_tt: Arc<crate::transposition::TranspositionCache<T, S>>,
+    mut alpha: isize,
+    beta: isize,
+    stats: Option<Arc<Stats<T::Player>>>,
+) -> futures::future::BoxFuture<'static, Result<isize, GameSolveError<T>>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    Box::pin(async move {
+        // positions that are scored without searching their moves are left to negamax.
+        let resolved = game.is_repeated_position()
+            || game.is_draw()
+            || game.is_repetition_draw()
+            || game.state().is_terminal();
+        let split = if resolved {
+            None
+        } else {
+            game.split_moves()
+                .map(|(eldest, younger)| (eldest, younger.collect::<Vec<_>>()))
+        };
+
+        let Some((eldest, younger)) = split else {
+            let mut map = shared_tt;
+            let options = NegamaxOptions::new(alpha, beta).with_stats(stats.as_deref());
+            return negamax_raw(&game, &mut map, options);
+        };
+
+        let board = game::play(Cow::Borrowed(&game), &eldest)
+            .map_err(|err| GameSolveError::MoveError::<T>(err))?;
+        let score =
+            -ybwc_search(board, Arc::clone(&shared_tt), -beta, -alpha, stats.clone()).await?;
+
+        if score >= beta {
+            return Ok(beta);
+        }
+        alpha = alpha.max(score);
+
+        let handles = younger.into_iter().map(|m| {
+            let game = game.clone();
+            let stats = stats.clone();
+            let mut map = Arc::clone(&shared_tt);
+
+            tokio::spawn(async move {
+                let board = game::play(Cow::Owned(game), &m)
+                    .map_err(|err| GameSolveError::MoveError::<T>(err))?;
+                let options = NegamaxOptions::new(-beta, -alpha).with_stats(stats.as_deref());
+                negamax_raw(&board, &mut map, options).map(|score| -score)
+            })
+        });
+
+        for score in futures::future::join_all(handles).await {
+            alpha = alpha.max(score.unwrap()?);
+        }
+
+        Ok(alpha.min(beta))
+    })
+}
+
+/// Parallelized version of `solve`.
+/// This requires the `rayon` feature to be enabled.
+///
+/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher.
+/// If you want to use your own hasher, use [`par_solve_with_hasher`].
+#[cfg(feature = "rayon")]
+pub async fn par_solve<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    game: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<isize, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    if cfg!(feature = "xxhash") {
+        use twox_hash::RandomXxHashBuilder64;
+        par_solve_with_hasher::<T, RandomXxHashBuilder64>(game, stats, cancellation_token).await
+    } else {
+        use std::collections::hash_map::RandomState;
+        par_solve_with_hasher::<T, RandomState>(game, stats, cancellation_token).await
+    }
+}
+
+/// Parallelized version of [`solve_all_positions`].
+/// This requires the `rayon` feature to be enabled.
+///
+/// Every position is solved in its own task, all sharing one transposition table.
+///
+/// **Warning**: this holds every reachable position in memory,
+/// so it's only practical for games with fewer than ~10^7 positions.
+#[cfg(feature = "rayon")]
+pub async fn par_solve_all_positions_with_hasher<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    initial: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<HashMap<T, isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    use crate::transposition::TranspositionCache;
+
+    let hashmap = Arc::new(TranspositionCache::<T, S>::new());
+
+    let handles = reachable_positions(initial)?.into_iter().map(|game| {
+        let stats = stats.clone();
+        let mut map = Arc::clone(&hashmap);
+
+        tokio::spawn(
+            async move { solve(&game, &mut map, stats.as_deref()).map(|score| (game, score)) },
+        )
+    });
+
+    let scores = futures::future::join_all(handles);
+
+    let scores = if let Some(cancellation_token) = &cancellation_token {
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                return Err(GameSolveError::Cancelled);
+            },
+            scores = scores => scores
+        }
+    } else {
+        scores.await
+    };
+
+    scores.into_iter().map(|score| score.unwrap()).collect()
+}
+
+/// Parallelized version of [`solve_all_positions`].
+/// This requires the `rayon` feature to be enabled.
+///
+/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher.
+/// If you want to use your own hasher, use [`par_solve_all_positions_with_hasher`].
+#[cfg(feature = "rayon")]
+pub async fn par_solve_all_positions<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    initial: &T,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<HashMap<T, isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    if cfg!(feature = "xxhash") {
+        use twox_hash::RandomXxHashBuilder64;
+        par_solve_all_positions_with_hasher::<T, RandomXxHashBuilder64>(
+            initial,
+            stats,
+            cancellation_token,
+        )
+        .await
+    } else {
+        use std::collections::hash_map::RandomState;
+        par_solve_all_positions_with_hasher::<T, RandomState>(initial, stats, cancellation_token)
+            .await
+    }
+}
+
+/// Solves every game of `games` in its own task, where `transposition_table`
+/// creates the table each task solves with.
+#[cfg(feature = "rayon")]
+async fn par_solve_each<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    TT: crate::transposition::SyncTranspositionTable<T> + 'static,
+>(
+    games: &[T],
+    transposition_table: impl Fn() -> TT,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<Vec<isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    let handles = games.iter().map(|game| {
+        let game = game.clone();
+        let stats = stats.clone();
+        let mut map = transposition_table();
+
+        tokio::spawn(async move { solve(&game, &mut map, stats.as_deref()) })
+    });
+
+    let scores = futures::future::join_all(handles);
+
+    let scores = if let Some(cancellation_token) = &cancellation_token {
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                return Err(GameSolveError::Cancelled);
+            },
+            scores = scores => scores
+        }
+    } else {
+        scores.await
+    };
+
+    scores.into_iter().map(|score| score.unwrap()).collect()
+}
+
+/// Solves every game of `games` in parallel, in the same order.
+/// This requires the `rayon` feature to be enabled.
+///
+/// Every game is solved against its own transposition table,
+/// which also allows you to pass in your own hasher.
+/// If the games are likely to share positions, use [`par_solve_batch_with_shared_tt`] instead.
+#[cfg(feature = "rayon")]
+pub async fn par_solve_batch_with_hasher<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    games: &[T],
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<Vec<isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    par_solve_each(
+        games,
+        HashMap::<T, Score, S>::default,
+        stats,
+        cancellation_token,
+    )
+    .await
+}
+
+/// Solves every game of `games` in parallel, in the same order.
+/// This requires the `rayon` feature to be enabled.
+///
+/// By default, this uses the cryptograpphically unsecure `XxHash64` hasher.
+/// If you want to use your own hasher, use [`par_solve_batch_with_hasher`].
+#[cfg(feature = "rayon")]
+pub async fn par_solve_batch<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+>(
+    games: &[T],
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<Vec<isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    if cfg!(feature = "xxhash") {
+        use twox_hash::RandomXxHashBuilder64;
+        par_solve_batch_with_hasher::<T, RandomXxHashBuilder64>(games, stats, cancellation_token)
+            .await
+    } else {
+        use std::collections::hash_map::RandomState;
+        par_solve_batch_with_hasher::<T, RandomState>(games, stats, cancellation_token).await
+    }
+}
+
+/// Solves every game of `games` in parallel, in the same order,
+/// where every game is solved against `shared_tt`.
+/// This requires the `rayon` feature to be enabled.
+///
+/// This pays off when the games share many positions,
+/// e.g. the same opening reached with different move orders.
+#[cfg(feature = "rayon")]
+pub async fn par_solve_batch_with_shared_tt<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    S,
+>(
+    games: &[T],
+    shared_tt: Arc<crate::transposition::TranspositionCache<T, S>>,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<Vec<isize>, GameSolveError<T>>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+    S: BuildHasher + Default + Sync + Send + Clone + 'static,
+{
+    par_solve_each(games, || Arc::clone(&shared_tt), stats, cancellation_token).await
+}
+
+// (the tests use `HashMap`s as transposition tables, and need `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::{collections::HashMap, convert::Infallible, hash::Hasher, sync::atomic::AtomicUsize};
+
+    use super::*;
+    use crate::{
+        game::{DefaultMoveError, ExactGame, Normal, NormalImpartial},
+        loopy::RepeatedPositionDetector,
+        player::PartizanPlayer,
+    };
+
+    /// Nim, where players take any amount of objects from a single heap in turn,
+    /// and taking the last object wins. Moves are a heap, and the amount to take from it.
+    ///
+    /// The player to move wins exactly when the heaps don't XOR to 0,
+    /// which makes this the reference game for the tests of other modules too.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub(crate) struct Nim {
+        pub(crate) heaps: Vec<usize>,
+        move_count: usize,
+        max_moves: usize,
+    }
+
+    impl Nim {
+        pub(crate) fn new(heaps: Vec<usize>) -> Self {
+            Self {
+                max_moves: heaps.iter().sum(),
+                heaps,
+                move_count: 0,
+            }
+        }
+
+        /// Whether the player to move loses, i.e. the heaps XOR to 0.
+        pub(crate) fn is_lost(&self) -> bool {
+            self.heaps.iter().fold(0, |xor, heap| xor ^ heap) == 0
+        }
+    }
+
+    /// A broken game of Nim, which has made more moves than it should be able to.
+    pub(crate) fn over_counted() -> Nim {
+        Nim {
+            heaps: vec![1],
+            move_count: 5,
+            max_moves: 1,
+        }
+    }
+
+    impl Normal for Nim {}
+    impl NormalImpartial for Nim {}
+    impl ExactGame for Nim {}
+
+    impl Game for Nim {
+        type Move = (usize, usize);
+        type Iter<'a> = std::vec::IntoIter<(usize, usize)>;
+        type MoveError = DefaultMoveError;
+        type Player = ImpartialPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.max_moves)
+        }
+
+        fn make_move(&mut self, &(heap, amount): &Self::Move) -> Result<(), Self::MoveError> {
+            let heap = self
+                .heaps
+                .get_mut(heap)
+                .ok_or(DefaultMoveError::OutOfBounds)?;
+            *heap = heap
+                .checked_sub(amount)
+                .ok_or(DefaultMoveError::IllegalMove)?;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            self.heaps
+                .iter()
+                .enumerate()
+                .flat_map(|(heap, &objects)| (1..=objects).map(move |amount| (heap, amount)))
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            <Self as Normal>::state(self)
+        }
+
+        fn player(&self) -> Self::Player {
+            ImpartialPlayer::Next
+        }
+    }
+
+    /// A game on a ring of three positions, where every move steps forward by one or two.
+    /// This game never ends on its own, so it relies on repetition detection -
+    /// which also bounds it to three moves, as the third always repeats a position.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Ring {
+        position: u8,
+        move_count: usize,
+        seen: RepeatedPositionDetector<u8>,
+        repeated: bool,
+    }
+
+    impl Hash for Ring {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.position.hash(state);
+            self.move_count.hash(state);
+        }
+    }
+
+    impl Game for Ring {
+        type Move = u8;
+        type Iter<'a> = core::array::IntoIter<u8, 2>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(3)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.position = (self.position + m) % 3;
+            self.move_count += 1;
+            self.repeated = self.seen.visit(&self.position);
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            [1, 2].into_iter()
+        }
+
+        fn is_repeated_position(&self) -> bool {
+            self.repeated
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Playable
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    #[test]
+    fn repeated_positions_are_draws() {
+        let mut seen = RepeatedPositionDetector::new();
+        seen.visit(&0);
+
+        let ring = Ring {
+            position: 0,
+            move_count: 0,
+            seen,
+            repeated: false,
+        };
+
+        assert_eq!(solve(&ring, &mut HashMap::new(), None).unwrap(), 0);
+    }
+
+    /// A walk from 0 to 2, one step at a time, where whoever reaches 2 wins.
+    /// Walks remember where they've been, but compare (and hash) only by where they are,
+    /// so walks with different histories transpose into each other.
+    #[derive(Clone, Debug)]
+    struct Walk {
+        position: u8,
+        move_count: usize,
+        visited: Vec<u8>,
+    }
+
+    impl PartialEq for Walk {
+        fn eq(&self, other: &Self) -> bool {
+            self.position == other.position && self.move_count == other.move_count
+        }
+    }
+
+    impl Eq for Walk {}
+
+    impl Hash for Walk {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.position.hash(state);
+            self.move_count.hash(state);
+        }
+    }
+
+    impl Game for Walk {
+        type Move = ();
+        type Iter<'a> = core::option::IntoIter<()>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(2)
+        }
+
+        fn make_move(&mut self, _m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.visited.push(self.position);
+            self.position += 1;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            (self.position < 2).then_some(()).into_iter()
+        }
+
+        fn is_repeated_position(&self) -> bool {
+            self.visited.contains(&self.position)
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.position == 2 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    #[test]
+    fn repetitions_dont_leak_between_paths() {
+        let walk = Walk {
+            position: 0,
+            move_count: 0,
+            visited: Vec::new(),
+        };
+        // the same position, but stepping to 1 repeats it, which draws.
+        let looped = Walk {
+            visited: vec![1],
+            ..walk.clone()
+        };
+
+        let mut transposition_table = HashMap::new();
+        assert_eq!(solve(&looped, &mut transposition_table, None).unwrap(), 0);
+        // the second player wins once the walk reaches 2.
+        assert_eq!(
+            solve(&walk, &mut transposition_table, None).unwrap(),
+            -(max_score(&walk).unwrap() - 2)
+        );
+    }
+
+    /// A game without any moves, in any state.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Stuck {
+        state: GameState<PartizanPlayer>,
+    }
+
+    impl Game for Stuck {
+        type Move = ();
+        type Iter<'a> = core::iter::Empty<()>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            0
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(1)
+        }
+
+        fn make_move(&mut self, _m: &Self::Move) -> Result<(), Self::MoveError> {
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            core::iter::empty()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            self.state
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::Left
+        }
+    }
+
+    impl stochastic::StochasticGame for Stuck {
+        type Outcome = ();
+
+        fn chance_outcomes(&self) -> impl Iterator<Item = (Self::Outcome, f64, Self)> {
+            core::iter::empty()
+        }
+    }
+
+    #[test]
+    fn games_without_moves_are_over() {
+        let stuck = Stuck {
+            state: GameState::Playable,
+        };
+        let max_score = bounded_max_score(&stuck).unwrap();
+
+        // games that are over are scored by their state.
+        for (state, score) in [
+            (GameState::Tie, 0),
+            (GameState::Win(PartizanPlayer::Left), max_score),
+            (GameState::Win(PartizanPlayer::Right), -max_score),
+        ] {
+            let over = Stuck { state };
+            assert_eq!(solve(&over, &mut HashMap::new(), None).unwrap(), score);
+            assert_eq!(
+                negamax_soft_fail(&over, &mut HashMap::new(), -max_score, max_score, None).unwrap(),
+                score
+            );
+        }
+
+        assert_eq!(
+            solve(&stuck, &mut HashMap::new(), None).unwrap(),
+            -max_score
+        );
+        assert_eq!(
+            negamax_soft_fail(&stuck, &mut HashMap::new(), -max_score, max_score, None).unwrap(),
+            -max_score
+        );
+
+        // every other solver agrees that the player to move lost.
+        assert_eq!(
+            solve_minimax(&stuck, &mut HashMap::new()).unwrap(),
+            -max_score
+        );
+        let graph = tree::GameGraph::build_graph(&stuck, 1).unwrap();
+        assert_eq!(tree::solve_on_graph(&graph, &stuck), Some(-max_score));
+        assert_eq!(stochastic::expectimax(&stuck, 1).unwrap(), -1.0);
+        assert_eq!(
+            pns::proof_number_search(&stuck).unwrap(),
+            GameState::Win(PartizanPlayer::Right)
+        );
+    }
+
+    /// How many moves have been made in a [`Sprint`], across every game of it.
+    static SPRINT_MOVES_MADE: AtomicUsize = AtomicUsize::new(0);
+
+    /// Players take turns running 1 or 2 steps, and whoever reaches the third step first wins.
+    /// It knows which moves win without making them, and counts the moves it does make.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Sprint {
+        position: usize,
+        move_count: usize,
+    }
+
+    impl Game for Sprint {
+        type Move = usize;
+        type Iter<'a> = core::array::IntoIter<usize, 2>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(3)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            SPRINT_MOVES_MADE.fetch_add(1, Ordering::Relaxed);
+            self.position += m;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            [1, 2].into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.position >= 3 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+
+        fn is_winning_move(&self, m: &Self::Move) -> bool {
+            self.position + m >= 3
+        }
+    }
+
+    #[test]
+    fn winning_moves_are_found_without_making_them() {
+        let sprint = Sprint {
+            position: 1,
+            move_count: 1,
+        };
+
+        SPRINT_MOVES_MADE.store(0, Ordering::Relaxed);
+        assert_eq!(
+            solve(&sprint, &mut HashMap::new(), None).unwrap(),
+            bounded_max_score(&sprint).unwrap() - 1
+        );
+        assert_eq!(SPRINT_MOVES_MADE.load(Ordering::Relaxed), 0);
+
+        // only the winning move is made, to return the game it leads to.
+        let won = sprint.find_immediately_resolvable_game().unwrap().unwrap();
+        assert_eq!(won.position, 3);
+        assert_eq!(SPRINT_MOVES_MADE.load(Ordering::Relaxed), 1);
+    }
+
+    /// A game that never ends, and doesn't bound its amount of moves.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Endless;
+
+    impl Game for Endless {
+        type Move = ();
+        type Iter<'a> = core::iter::Once<()>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            0
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            None
+        }
+
+        fn make_move(&mut self, _m: &Self::Move) -> Result<(), Self::MoveError> {
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            core::iter::once(())
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Playable
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::Left
+        }
+    }
+
+    #[test]
+    fn unbounded_games_are_not_solved() {
+        assert_eq!(game::upper_bound(&Endless), None);
+        assert!(matches!(
+            solve(&Endless, &mut HashMap::new(), None),
+            Err(GameSolveError::UnboundedGame)
+        ));
+        assert!(matches!(
+            depth_first_solve(&Endless, &mut HashMap::new(), None),
+            Err(GameSolveError::UnboundedGame)
+        ));
+    }
+
+    /// A subtraction game, where players take one or two tokens in turn, and taking the last wins.
+    /// Solving it can be given narrower bounds than the ones derived from its amount of moves.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub(crate) struct Subtraction {
+        tokens: usize,
+        move_count: usize,
+        max_moves: usize,
+        bounds: Option<(isize, isize)>,
+    }
+
+    impl Subtraction {
+        /// A game of `tokens` tokens, bounded by its amount of moves.
+        pub(crate) fn new(tokens: usize) -> Self {
+            Self {
+                tokens,
+                move_count: 0,
+                max_moves: tokens,
+                bounds: None,
+            }
+        }
+    }
+
+    impl Game for Subtraction {
+        type Move = usize;
+        type Iter<'a> = core::iter::Take<core::ops::RangeInclusive<usize>>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.max_moves)
+        }
+
+        fn min_score(&self) -> Option<isize> {
+            self.bounds
+                .map(|(min, _)| min)
+                .or_else(|| game::min_score(self))
+        }
+
+        fn max_score(&self) -> Option<isize> {
+            self.bounds
+                .map(|(_, max)| max)
+                .or_else(|| game::max_score(self))
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.tokens -= m;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            (1..=2).take(self.tokens)
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.tokens == 0 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    /// The subtraction game, where every move can be made with or without a flourish,
+    /// which doesn't change the position.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Flourish {
+        tokens: usize,
+        move_count: usize,
+        dedupe: bool,
+    }
+
+    impl Game for Flourish {
+        type Move = (usize, bool);
+        type Iter<'a> = alloc::vec::IntoIter<(usize, bool)>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.tokens + self.move_count)
+        }
+
+        fn make_move(&mut self, (amount, _): &Self::Move) -> Result<(), Self::MoveError> {
+            self.tokens -= amount;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            (1..=self.tokens.min(2))
+                .flat_map(|amount| [(amount, false), (amount, true)])
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+
+        fn canonical_moves(&self) -> Self::Iter<'_> {
+            if !self.dedupe {
+                return self.possible_moves();
+            }
+
+            self.possible_moves()
+                .filter(|(_, flourish)| !flourish)
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.tokens == 0 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    #[test]
+    fn canonical_moves_skip_duplicate_subtrees() {
+        let explored = |dedupe| {
+            let game = Flourish {
+                tokens: 10,
+                move_count: 0,
+                dedupe,
+            };
+            let stats = Stats::new(game.player(), 0);
+
+            let score = solve(&game, &mut HashMap::new(), Some(&stats)).unwrap();
+            (score, stats.states_explored.load(Ordering::Relaxed))
+        };
+
+        let (score, duplicated) = explored(false);
+        let (deduped_score, deduped) = explored(true);
+        assert_eq!(score, deduped_score);
+        assert!(deduped < duplicated, "{deduped} >= {duplicated}");
+    }
+
+    /// The subtraction game (taking one or two tokens), where a position is noisy
+    /// while the player to move can take every token left, which is its only noisy move.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Countdown {
+        tokens: usize,
+        move_count: usize,
+    }
+
+    impl Game for Countdown {
+        type Move = usize;
+        type Iter<'a> = alloc::vec::IntoIter<usize>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.tokens + self.move_count)
+        }
+
+        fn make_move(&mut self, amount: &Self::Move) -> Result<(), Self::MoveError> {
+            self.tokens -= amount;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            (1..=self.tokens.min(2)).collect::<Vec<_>>().into_iter()
+        }
+
+        fn is_quiet(&self) -> bool {
+            self.tokens > 2
+        }
+
+        fn noisy_moves(&self) -> Self::Iter<'_> {
+            vec![self.tokens].into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.tokens == 0 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    #[test]
+    fn noisy_positions_are_searched_past_the_depth() {
+        let score_at_horizon = |tokens| {
+            let game = Countdown {
+                tokens,
+                move_count: 0,
+            };
+            let max_score = bounded_max_score(&game).unwrap();
+            let options = NegamaxOptions::new(-max_score, max_score).with_depth(0);
+            negamax_raw(&game, &mut HashMap::new(), options).unwrap()
+        };
+
+        // quiet positions past the depth are still guessed to be ties,
+        assert_eq!(score_at_horizon(3), 0);
+
+        // but noisy ones are settled first.
+        for tokens in [1, 2] {
+            let game = Countdown {
+                tokens,
+                move_count: 0,
+            };
+            assert_eq!(
+                score_at_horizon(tokens),
+                solve(&game, &mut HashMap::new(), None).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn tighter_score_bounds_solve_faster() {
+        let explored = |bounds| {
+            let game = Subtraction {
+                tokens: 16,
+                move_count: 0,
+                max_moves: 16,
+                bounds,
+            };
+            let stats = Stats::new(game.player(), 0);
+
+            let score = solve(&game, &mut HashMap::new(), Some(&stats)).unwrap();
+            (score, stats.states_explored.into_inner())
+        };
+
+        let (score, loose) = explored(None);
+        let (tight_score, tight) = explored(Some((score - 1, score + 1)));
+
+        assert_eq!(score, tight_score);
+        assert!(tight < loose, "{tight} states explored, over {loose}");
+    }
+
+    #[test]
+    fn memoized_solves_reuse_exact_scores() {
+        let game = Subtraction {
+            tokens: 12,
+            move_count: 0,
+            max_moves: 12,
+            bounds: None,
+        };
+        let mut transposition_table = HashMap::new();
+        let score = solve(&game, &mut transposition_table, None).unwrap();
+
+        let stats = Stats::new(game.player(), 0);
+
+        // solving stored the exact score, so solving again doesn't search anything.
+        assert_eq!(
+            score,
+            memoized_solve(&game, &mut transposition_table, Some(&stats)).unwrap()
+        );
+        assert_eq!(stats.states_explored.into_inner(), 0);
+        assert_eq!(stats.cache_hits.into_inner(), 1);
+
+        // the children scored by `move_scores` are solved, and stored, the same way.
+        let scores = move_scores(&game, &mut transposition_table, None)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        for (amount, score) in scores {
+            let mut child = game.clone();
+            child.make_move(&amount).unwrap();
+            assert_eq!(transposition_table.get(&child), Some(&Score::Exact(-score)));
+        }
+    }
+
+    #[test]
+    fn forced_wins_settle_solves() {
+        for tokens in 1..=10 {
+            let game = Subtraction {
+                tokens,
+                move_count: 0,
+                max_moves: tokens,
+                bounds: None,
+            };
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+
+            let stats = Stats::new(game.player(), 0);
+            let config = SolverConfig::new().with_forced_win_depth(5);
+            let forced = solve_with_config(&game, &mut HashMap::new(), &config, Some(&stats));
+            assert_eq!(forced.unwrap(), score, "{tokens} tokens");
+
+            // wins within 5 moves are found without searching, but nothing else is.
+            let settled = tokens % 3 != 0 && tokens <= 8;
+            assert_eq!(
+                stats.states_explored.into_inner() == 0,
+                settled,
+                "{tokens} tokens"
+            );
+        }
+    }
+
+    #[test]
+    fn ensembles_settle_on_the_median() {
+        let game = Subtraction {
+            tokens: 7,
+            move_count: 0,
+            max_moves: 7,
+            bounds: None,
+        };
+        let score = solve(&game, &mut HashMap::new(), None).unwrap();
+
+        let ensemble = EnsembleSolver::new()
+            .with_solver(|game| solve(game, &mut HashMap::new(), None).unwrap())
+            .with_solver(move |_| score)
+            .with_solver(|_| isize::MIN)
+            .with_solver(|_| isize::MAX);
+
+        assert_eq!(ensemble.solve(&game), score);
+        assert_eq!(ensemble.solve_agreement(&game), (score, 0.5));
+
+        let unanimous = EnsembleSolver::new().with_solver(move |_| score);
+        assert_eq!(unanimous.solve_agreement(&game), (score, 1.0));
+    }
+
+    /// A table that remembers which boards were prefetched, and which were looked up without one.
+    #[derive(Default)]
+    struct PrefetchTracker {
+        table: HashMap<Subtraction, Score>,
+        prefetched: core::cell::RefCell<HashSet<Subtraction>>,
+        unprefetched: core::cell::RefCell<Vec<Subtraction>>,
+    }
+
+    impl TranspositionTable<Subtraction> for PrefetchTracker {
+        fn get(&self, board: &Subtraction) -> Option<Score> {
+            if !self.prefetched.borrow().contains(board) {
+                self.unprefetched.borrow_mut().push(board.clone());
+            }
+            self.table.get(board).copied()
+        }
+
+        fn insert(&mut self, board: Subtraction, score: Score) {
+            self.table.insert(board, score);
+        }
+
+        fn has(&self, board: &Subtraction) -> bool {
+            self.table.contains_key(board)
+        }
+
+        fn prefetch(&self, board: &Subtraction) {
+            self.prefetched.borrow_mut().insert(board.clone());
+        }
+    }
+
+    #[test]
+    fn searched_boards_are_prefetched() {
+        let game = Subtraction {
+            tokens: 8,
+            move_count: 0,
+            max_moves: 8,
+            bounds: None,
+        };
+
+        let mut transposition_table = PrefetchTracker::default();
+        solve(&game, &mut transposition_table, None).unwrap();
+
+        assert!(!transposition_table.prefetched.borrow().is_empty());
+        // only the position being solved is looked up without being reached by a move.
+        assert!(transposition_table
+            .unprefetched
+            .into_inner()
+            .iter()
+            .all(|board| *board == game));
+    }
+
+    /// A tied game that the left player values more than the right one.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct ValuedTie;
+
+    impl Game for ValuedTie {
+        type Move = ();
+        type Iter<'a> = core::iter::Empty<()>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            0
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(0)
+        }
+
+        fn make_move(&mut self, _m: &Self::Move) -> Result<(), Self::MoveError> {
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            core::iter::empty()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Tie
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::Left
+        }
+
+        fn evaluate(&self, maximizer: Self::Player) -> Option<isize> {
+            Some(if maximizer == PartizanPlayer::Left {
+                3
+            } else {
+                -1
+            })
+        }
+    }
+
+    #[test]
+    fn minimax_evaluates_ties() {
+        assert_eq!(
+            minimax(
+                &ValuedTie,
+                &mut HashMap::new(),
+                isize::MIN,
+                isize::MAX,
+                true
+            )
+            .unwrap(),
+            3
+        );
+        assert_eq!(
+            minimax(
+                &ValuedTie,
+                &mut HashMap::new(),
+                isize::MIN,
+                isize::MAX,
+                false
+            )
+            .unwrap(),
+            -1
+        );
+    }
+
+    /// A buggy game that is won right away, but whose moves don't know that.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct AlreadyWon;
+
+    impl Game for AlreadyWon {
+        type Move = ();
+        type Iter<'a> = core::iter::Once<()>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            0
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(1)
+        }
+
+        fn make_move(&mut self, _m: &Self::Move) -> Result<(), Self::MoveError> {
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            core::iter::once(())
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Win(PartizanPlayer::Right)
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::Left
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exploring a terminal state")]
+    fn terminal_states_are_not_expanded() {
+        let mut transposition_table = HashMap::new();
+        let _ = move_scores(&AlreadyWon, &mut transposition_table, None).collect::<Vec<_>>();
+    }
+
+    /// Collects `scores`, sorted by move, to compare them regardless of the order they came in.
+    fn sorted_scores(scores: CollectedMoves<Nim>) -> Vec<((usize, usize), isize)> {
+        let mut scores = scores.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        scores.sort();
+        scores
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "move_count exceeded max_moves")]
+    fn over_counted_games_fail_fast() {
+        let _ = solve(&over_counted(), &mut HashMap::new(), None);
+    }
+
+    #[test]
+    fn soft_fail_matches_solve() {
+        for heaps in [vec![3], vec![2, 2], vec![1, 2, 3], vec![3, 4]] {
+            let nim = Nim::new(heaps);
+            let bound = game::upper_bound(&nim).unwrap();
+
+            let solved = solve(&nim, &mut HashMap::new(), None).unwrap();
+            let soft_fail =
+                negamax_soft_fail(&nim, &mut HashMap::new(), -bound, bound + 1, None).unwrap();
+
+            assert_eq!(solved, soft_fail);
+        }
+    }
+
+    /// The moves out of each position of [`Branch`].
+    /// Positions 3 and 6 have none, so they're lost for whoever has to move there.
+    const BRANCHES: [&[u8]; 7] = [&[2, 1], &[5], &[3], &[], &[], &[6], &[]];
+
+    /// A walk down [`BRANCHES`] from position 0: the first player loses after the first move,
+    /// and wins after the second.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Branch {
+        position: u8,
+        move_count: usize,
+    }
+
+    impl Game for Branch {
+        type Move = u8;
+        type Iter<'a> = core::iter::Copied<core::slice::Iter<'a, u8>>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(4)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.position = *m;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            BRANCHES[self.position as usize].iter().copied()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Playable
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    #[test]
+    fn null_windows_past_beta_arent_researched() {
+        let root = Branch {
+            position: 0,
+            move_count: 0,
+        };
+        let score = solve(&root, &mut HashMap::new(), None).unwrap();
+        let researches = |beta| {
+            let stats = Stats::new(root.player(), 0);
+            let max_score = max_score(&root).unwrap();
+            negamax_soft_fail(&root, &mut HashMap::new(), -max_score, beta, Some(&stats)).unwrap();
+            stats.researches.into_inner()
+        };
+
+        // the second move's null window fails high, which is worth searching again
+        // if it's still inside of the window...
+        assert_eq!(researches(score + 1), 1);
+        // ...but not if it's already past beta, since it's cut off either way.
+        assert_eq!(researches(score), 0);
+    }
+
+    #[test]
+    fn depth_first_solve_matches_solve() {
+        for heaps in [
+            vec![3],
+            vec![2, 2],
+            vec![1, 2, 3],
+            vec![3, 4],
+            vec![5, 5, 3, 3],
+        ] {
+            let nim = Nim::new(heaps);
+
+            assert_eq!(
+                depth_first_solve(&nim, &mut HashMap::new(), None).unwrap(),
+                solve(&nim, &mut HashMap::new(), None).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn ranked_moves_are_sorted() {
+        let nim = Nim::new(vec![1, 2, 3]);
+        let ranked = move_scores_ranked(&nim, &mut HashMap::new(), None).unwrap();
+
+        assert_eq!(ranked.len(), 1 + 2 + 3);
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(
+            best_n_moves(&nim, &mut HashMap::new(), 1, None).unwrap(),
+            vec![ranked[0]]
+        );
+    }
+
+    #[test]
+    fn lazy_ranked_moves_match_ranked_moves() {
+        for heaps in [vec![1, 2, 3], vec![2, 2], vec![3, 4, 5]] {
+            let nim = Nim::new(heaps);
+            let ranked = move_scores_ranked(&nim, &mut HashMap::new(), None).unwrap();
+
+            let mut map = HashMap::new();
+            let mut lazy = ranked_moves(&nim, &mut map, None)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            // moves with equal scores may come in any order
+            assert_eq!(
+                lazy.iter().map(|(_, score)| *score).collect::<Vec<_>>(),
+                ranked.iter().map(|(_, score)| *score).collect::<Vec<_>>()
+            );
+            let mut ranked = ranked;
+            lazy.sort();
+            ranked.sort();
+            assert_eq!(lazy, ranked);
+        }
+    }
+
+    #[test]
+    fn worst_first_move_order_keeps_scores() {
+        // searching the moves backwards means the best move often isn't the first one searched.
+        struct Reversed;
+
+        impl MoveOrderer<Nim> for Reversed {
+            fn order_moves(&self, _game: &Nim, moves: &mut [(usize, usize)]) {
+                moves.reverse();
+            }
+        }
+
+        for heaps in [vec![2, 3, 4], vec![1, 2, 3, 4], vec![4, 4, 1], vec![5, 1]] {
+            let nim = Nim::new(heaps.clone());
+            let score = solve(&nim, &mut HashMap::new(), None).unwrap();
+
+            let config = SolverConfig::new().with_move_orderer(Reversed);
+            assert_eq!(
+                solve_with_config(&nim, &mut HashMap::new(), &config, None).unwrap(),
+                score,
+                "heaps {heaps:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn negamax_raw_options() {
+        let nim = Nim::new(vec![2, 3, 4]);
+        let max_score = max_score(&nim).unwrap();
+
+        assert_eq!(
+            negamax_raw(
+                &nim,
+                &mut HashMap::new(),
+                NegamaxOptions::new(-max_score, max_score)
+            )
+            .unwrap(),
+            solve(&nim, &mut HashMap::new(), None).unwrap()
+        );
+
+        // nim is never tied, so a search past the first move only guesses ties.
+        let options = NegamaxOptions::new(-max_score, max_score).with_depth(0);
+        assert_eq!(negamax_raw(&nim, &mut HashMap::new(), options).unwrap(), 0);
+
+        let cancelled = Arc::new(AtomicBool::new(true));
+        let options = NegamaxOptions::new(-max_score, max_score).with_cancelled(cancelled);
+        assert!(matches!(
+            negamax_raw(&nim, &mut HashMap::new(), options),
+            Err(GameSolveError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn iterative_deepening_windows_narrow() {
+        let nim = Nim::new(vec![2, 3, 4]);
+        let score = solve(&nim, &mut HashMap::new(), None).unwrap();
+
+        let mut transposition_table = HashMap::new();
+        let windows = iterative_deepen(&nim, &mut transposition_table)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(windows.last(), Some(&(score, score)));
+        for (alpha, beta) in &windows {
+            assert!((*alpha..=*beta).contains(&score));
+        }
+        for pair in windows.windows(2) {
+            let [(alpha, beta), (next_alpha, next_beta)] = pair else {
+                unreachable!()
+            };
+            assert!(next_alpha >= alpha && next_beta <= beta);
+        }
+
+        // stopping early still bounds the score.
+        let (alpha, beta) = iterative_deepen(&nim, &mut HashMap::new())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!((alpha..=beta).contains(&score));
+    }
+
+    #[test]
+    fn opening_moves_converge() {
+        let nim = Nim::new(vec![2, 3]);
+        let mut map = HashMap::new();
+
+        let openings = opening_moves(&nim, 10, &mut map, None).unwrap();
+        let (depth, best_move, score) = *openings.last().unwrap();
+
+        // the whole game fits in 5 moves, so deeper searches aren't run
+        assert!(depth <= 5);
+        assert_eq!(openings.len(), depth);
+        assert_eq!(score, solve(&nim, &mut HashMap::new(), None).unwrap());
+        assert!(
+            sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect())
+                .contains(&(best_move, score))
+        );
+
+        // depth-limited results shouldn't pollute the transposition table
+        assert_eq!(solve(&nim, &mut map, None).unwrap(), score);
+    }
+
+    #[test]
+    fn all_positions_are_solved() {
+        let nim = Nim::new(vec![2, 3]);
+
+        let scores = solve_all_positions(&nim, &mut HashMap::new(), None).unwrap();
+        assert_eq!(
+            scores[&nim],
+            solve(&nim, &mut HashMap::new(), None).unwrap()
+        );
+
+        for (position, score) in &scores {
+            assert_eq!(*score, solve(position, &mut HashMap::new(), None).unwrap());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    mod parallel {
+        use std::{collections::hash_map::RandomState, sync::Mutex};
+
+        use super::*;
+        use crate::transposition::TranspositionCache;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_solve_matches_solve() {
+            for heaps in [vec![3], vec![2, 2], vec![1, 2, 3], vec![3, 4]] {
+                let nim = Nim::new(heaps);
+
+                assert_eq!(
+                    solve(&nim, &mut HashMap::new(), None).unwrap(),
+                    par_solve(&nim, None, None).await.unwrap()
+                );
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_solves_honour_score_bounds() {
+            let game = Subtraction::new(16);
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+            let bounded = Subtraction {
+                bounds: Some((score - 1, score + 1)),
+                ..game.clone()
+            };
+
+            // the bounds narrow the probes, like they do for `solve`.
+            let loose = Arc::new(Stats::new(game.player(), 0));
+            let tight = Arc::new(Stats::new(game.player(), 0));
+            assert_eq!(
+                par_solve(&game, Some(Arc::clone(&loose)), None)
+                    .await
+                    .unwrap(),
+                score
+            );
+            assert_eq!(
+                par_solve(&bounded, Some(Arc::clone(&tight)), None)
+                    .await
+                    .unwrap(),
+                score
+            );
+            assert!(
+                tight.states_explored.load(Ordering::Relaxed)
+                    < loose.states_explored.load(Ordering::Relaxed)
+            );
+
+            let max_score = max_score(&game).unwrap();
+            let shared_tt = Arc::new(TranspositionCache::<Subtraction, RandomState>::new());
+            assert_eq!(
+                ybwc_solve(&bounded, shared_tt, -max_score, max_score, None)
+                    .await
+                    .unwrap(),
+                score
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn ybwc_solve_matches_solve() {
+            for heaps in [
+                vec![3],
+                vec![2, 2],
+                vec![1, 2, 3],
+                vec![3, 4],
+                vec![2, 3, 4],
+            ] {
+                let nim = Nim::new(heaps);
+                let max_score = max_score(&nim).unwrap();
+                let shared_tt = Arc::new(TranspositionCache::<Nim, RandomState>::with_capacity(
+                    10_000,
+                ));
+
+                assert_eq!(
+                    solve(&nim, &mut HashMap::new(), None).unwrap(),
+                    ybwc_solve(&nim, shared_tt, -max_score, max_score, None)
+                        .await
+                        .unwrap()
+                );
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_solve_batch_matches_solve() {
+            let games = [vec![3], vec![2, 2], vec![1, 2, 3], vec![3, 4]].map(Nim::new);
+            let scores = games
+                .iter()
+                .map(|nim| solve(nim, &mut HashMap::new(), None).unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(par_solve_batch(&games, None, None).await.unwrap(), scores);
+
+            let shared_tt = Arc::new(TranspositionCache::<Nim, RandomState>::with_capacity(
+                10_000,
+            ));
+            assert_eq!(
+                par_solve_batch_with_shared_tt(&games, shared_tt, None, None)
+                    .await
+                    .unwrap(),
+                scores
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_solve_all_positions_matches_solve_all_positions() {
+            let nim = Nim::new(vec![2, 3]);
+
+            assert_eq!(
+                par_solve_all_positions(&nim, None, None).await.unwrap(),
+                solve_all_positions(&nim, &mut HashMap::new(), None).unwrap()
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_move_scores_matches_move_scores() {
+            let nim = Nim::new(vec![2, 3, 4]);
+
+            assert_eq!(
+                sorted_scores(par_move_scores(&nim, None, None).await),
+                sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect())
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn seeds_dont_change_scores() {
+            let nim = Nim::new(vec![2, 3, 4]);
+            let expected = sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect());
+
+            for seed in [0, 1, 0xdead_beef] {
+                assert_eq!(
+                    sorted_scores(par_move_scores_with_seed(&nim, seed, None, None).await),
+                    expected,
+                    "seed {seed}"
+                );
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn progress_is_reported_for_every_move() {
+            let nim = Nim::new(vec![2, 3, 4]);
+            let done = Mutex::new(Vec::new());
+
+            let scores = par_move_scores_with_progress(
+                &nim,
+                |m, score| done.lock().unwrap().push((m, score)),
+                None,
+                None,
+            )
+            .await;
+            let scores = sorted_scores(scores);
+
+            let mut done = done.into_inner().unwrap();
+            done.sort();
+            assert_eq!(done, scores);
+            assert_eq!(
+                scores,
+                sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect())
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn lazy_par_move_scores_stop_at_winning_move() {
+            // taking the whole heap wins right away
+            let nim = Nim::new(vec![5]);
+            let scores = move_scores_lazy_parallel(&nim, None, None)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(scores.last(), Some(&((0, 5), max_score(&nim).unwrap() - 1)));
+
+            // without such a move, every move is solved
+            let nim = Nim::new(vec![2, 3, 4]);
+            assert_eq!(
+                sorted_scores(move_scores_lazy_parallel(&nim, None, None).await),
+                sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect())
+            );
+        }
+
+        // (the solve blocks a worker of its own, so another one is left to abort it)
+        #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+        async fn aborted_move_scores_stop_solving() {
+            // far too big to solve before the task is aborted
+            let nim = Nim::new(vec![7, 8, 9, 10, 11]);
+            let stats = Arc::new(Stats::new(nim.player(), nim.move_count()));
+            let shared_tt = Arc::new(TranspositionCache::<Nim, RandomState>::new());
+
+            let handle =
+                spawn_move_score(&nim, (0, 1), &shared_tt, &Some(Arc::clone(&stats)), &None);
+            tokio::time::sleep(core::time::Duration::from_millis(50)).await;
+            handle.abort();
+            assert!(handle.await.unwrap_err().is_cancelled());
+
+            // give the solve a moment to notice, then make sure it's no longer searching.
+            tokio::time::sleep(core::time::Duration::from_millis(50)).await;
+            let explored = stats.states_explored.load(Ordering::Relaxed);
+            tokio::time::sleep(core::time::Duration::from_millis(100)).await;
+            assert!(explored > 0);
+            assert_eq!(stats.states_explored.load(Ordering::Relaxed), explored);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn par_move_scores_reuses_shared_tt() {
+            let nim = Nim::new(vec![2, 3, 4]);
+            let expected = sorted_scores(move_scores(&nim, &mut HashMap::new(), None).collect());
+            let shared_tt = Arc::new(TranspositionCache::<Nim, RandomState>::with_capacity(
+                10_000,
+            ));
+
+            let first =
+                par_move_scores_with_shared_tt(&nim, Arc::clone(&shared_tt), None, None).await;
+            assert_eq!(sorted_scores(first), expected);
+            assert!(shared_tt.iter().next().is_some());
+
+            // the second run should be answered from the table the first one filled.
+            let stats = Arc::new(Stats::new(nim.player(), nim.move_count()));
+            let second =
+                par_move_scores_with_shared_tt(&nim, shared_tt, Some(Arc::clone(&stats)), None)
+                    .await;
+            assert_eq!(sorted_scores(second), expected);
+            assert!(stats.cache_hits.load(Ordering::Relaxed) > 0);
+        }
+    }
+}
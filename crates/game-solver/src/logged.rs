@@ -0,0 +1,248 @@
+//! A game wrapper that records every move made on it, to replay how a position was reached.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use crate::game::{Game, GameState};
+
+/// Every move made on a [`LoggedGame`], in order.
+pub struct GameChangeLog<T: Game>(pub Vec<T::Move>);
+
+impl<T: Game> GameChangeLog<T> {
+    /// Makes every logged move on `target`, in order.
+    ///
+    /// If a move fails, the moves before it stay made.
+    pub fn replay_on(&self, target: &mut T) -> Result<(), T::MoveError> {
+        for m in &self.0 {
+            target.make_move(m)?;
+        }
+
+        Ok(())
+    }
+
+    /// The logged moves.
+    pub fn moves(&self) -> &[T::Move] {
+        &self.0
+    }
+}
+
+impl<T: Game> Clone for GameChangeLog<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Game> Debug for GameChangeLog<T>
+where
+    T::Move: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GameChangeLog").field(&self.0).finish()
+    }
+}
+
+/// Wraps a game, logging every [`Game::make_move`] call into a [`GameChangeLog`],
+/// to debug how a problematic position was reached.
+///
+/// Every other method is delegated to the wrapped game, except for
+/// [`Game::find_immediately_resolvable_game_among`] and [`Game::canonical_form`],
+/// which use their default implementations so the positions they return keep accurate logs.
+/// The log is ignored when comparing, hashing, or formatting positions,
+/// so logged games can share transposition tables.
+#[derive(Clone)]
+pub struct LoggedGame<T: Game> {
+    game: T,
+    log: Vec<T::Move>,
+}
+
+impl<T: Game> LoggedGame<T> {
+    pub fn new(game: T) -> Self {
+        Self {
+            game,
+            log: Vec::new(),
+        }
+    }
+
+    /// The wrapped game.
+    pub fn inner(&self) -> &T {
+        &self.game
+    }
+
+    /// The moves made so far.
+    pub fn log(&self) -> &[T::Move] {
+        &self.log
+    }
+
+    pub fn into_log(self) -> GameChangeLog<T> {
+        GameChangeLog(self.log)
+    }
+}
+
+impl<T: Game + Debug> Debug for LoggedGame<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.game.fmt(f)
+    }
+}
+
+impl<T: Game + PartialEq> PartialEq for LoggedGame<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.game == other.game
+    }
+}
+
+impl<T: Game + Eq> Eq for LoggedGame<T> {}
+
+impl<T: Game + Hash> Hash for LoggedGame<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.game.hash(state);
+    }
+}
+
+impl<T: Game> Game for LoggedGame<T> {
+    type Move = T::Move;
+    type Iter<'a> = T::Iter<'a> where T: 'a;
+    type MoveError = T::MoveError;
+    type Player = T::Player;
+
+    fn move_count(&self) -> usize {
+        self.game.move_count()
+    }
+
+    fn max_moves(&self) -> Option<usize> {
+        self.game.max_moves()
+    }
+
+    fn min_score(&self) -> Option<isize> {
+        self.game.min_score()
+    }
+
+    fn max_score(&self) -> Option<isize> {
+        self.game.max_score()
+    }
+
+    fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+        self.game.make_move(m)?;
+        self.log.push(m.clone());
+        Ok(())
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        self.game.possible_moves()
+    }
+
+    fn canonical_moves(&self) -> Self::Iter<'_> {
+        self.game.canonical_moves()
+    }
+
+    fn is_quiet(&self) -> bool {
+        self.game.is_quiet()
+    }
+
+    fn noisy_moves(&self) -> Self::Iter<'_> {
+        self.game.noisy_moves()
+    }
+
+    fn is_winning_move(&self, m: &Self::Move) -> bool {
+        self.game.is_winning_move(m)
+    }
+
+    fn score_after_winning_move(&self, m: &Self::Move) -> Option<isize> {
+        self.game.score_after_winning_move(m)
+    }
+
+    fn move_to_index(&self, m: &Self::Move) -> Option<usize> {
+        self.game.move_to_index(m)
+    }
+
+    fn move_from_index(&self, index: usize) -> Option<Self::Move> {
+        self.game.move_from_index(index)
+    }
+
+    fn move_to_index_count(&self) -> Option<usize> {
+        self.game.move_to_index_count()
+    }
+
+    fn display_move(&self, m: &Self::Move) -> String
+    where
+        Self::Move: Debug,
+    {
+        self.game.display_move(m)
+    }
+
+    fn moves_equal(&self, a: &Self::Move, b: &Self::Move) -> bool
+    where
+        Self::Move: PartialEq,
+    {
+        self.game.moves_equal(a, b)
+    }
+
+    fn is_draw(&self) -> bool {
+        self.game.is_draw()
+    }
+
+    fn is_repeated_position(&self) -> bool {
+        self.game.is_repeated_position()
+    }
+
+    fn repetition_count(&self) -> usize {
+        self.game.repetition_count()
+    }
+
+    fn is_repetition_draw(&self) -> bool {
+        self.game.is_repetition_draw()
+    }
+
+    fn evaluate(&self, maximizer: Self::Player) -> Option<isize> {
+        self.game.evaluate(maximizer)
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        self.game.state()
+    }
+
+    fn player(&self) -> Self::Player {
+        self.game.player()
+    }
+}
+
+// (the tests use the Nim test game, which needs `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{solve, tests::Nim};
+
+    #[test]
+    fn logged_games_replay() {
+        let nim = Nim::new(vec![2, 3]);
+        let mut logged = LoggedGame::new(nim.clone());
+        logged.make_move(&(0, 1)).unwrap();
+        logged.make_move(&(1, 3)).unwrap();
+        // failed moves aren't logged.
+        assert!(logged.make_move(&(2, 1)).is_err());
+
+        assert_eq!(
+            solve(&logged, &mut HashMap::new(), None).unwrap(),
+            solve(logged.inner(), &mut HashMap::new(), None).unwrap()
+        );
+
+        let played = logged.inner().clone();
+        let log = logged.into_log();
+        assert_eq!(log.moves(), [(0, 1), (1, 3)]);
+
+        let mut replayed = nim;
+        log.replay_on(&mut replayed).unwrap();
+        assert_eq!(replayed, played);
+    }
+
+    #[test]
+    fn moves_equal_is_forwarded() {
+        let logged = LoggedGame::new(Nim::new(vec![2, 3]));
+        assert!(logged.moves_equal(&(1, 3), &(1, 3)));
+        assert!(!logged.moves_equal(&(1, 3), &(1, 2)));
+    }
+}
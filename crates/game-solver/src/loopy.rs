@@ -1,15 +1,17 @@
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::marker::PhantomData;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
 use smallvec::SmallVec;
 
 /// We handle loopy games with a custom struct, `LoopyTracker`, which is a
 /// HashSet of some state T. This is used to keep track of the states that
 /// have been visited, and if a state has been visited, we can handle it appropriately.
-/// 
+///
 /// `LoopyTracker` should be updated at `Game::make_move` and checked in `Game::state`.
-/// 
+///
 /// We say `T` is the primary type, and `S` is some representation of `T` without the `LoopyTracker`.
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -18,7 +20,10 @@ pub struct LoopyTracker<S: Eq + Hash, T: Eq + Hash> {
     _phantom: PhantomData<S>,
 }
 
-pub trait Loopy<S: Hash + Eq> where Self: Eq + Hash + Sized {
+pub trait Loopy<S: Hash + Eq>
+where
+    Self: Eq + Hash + Sized,
+{
     fn tracker_mut(&mut self) -> &mut LoopyTracker<S, Self>;
     fn tracker(&self) -> &LoopyTracker<S, Self>;
 
@@ -36,7 +41,7 @@ impl<S: Eq + Hash, T: Eq + Hash + Loopy<S>> LoopyTracker<S, T> {
 
     /// Check if a state has been visited.
     pub fn has_visited(&self, state: &T) -> bool {
-        self.visited.contains(&state)
+        self.visited.contains(state)
     }
 
     /// Mark a state as visited.
@@ -62,8 +67,43 @@ impl<S: Eq + Hash, T: Eq + Hash + Loopy<S>> Default for LoopyTracker<S, T> {
     }
 }
 
+/// A simpler alternative to `LoopyTracker` for games that only need to know
+/// whether a position has been seen before, e.g. to implement `Game::is_repeated_position`.
+///
+/// Since `HashSet` isn't `Hash`, games that store this detector
+/// should leave it out of their `Hash` implementation.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatedPositionDetector<T: Hash + Eq>(HashSet<T>);
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq + Clone> RepeatedPositionDetector<T> {
+    /// Create a new `RepeatedPositionDetector`.
+    pub fn new() -> Self {
+        Self(HashSet::new())
+    }
+
+    /// Marks a position as visited,
+    /// returning true if it was already seen.
+    pub fn visit(&mut self, position: &T) -> bool {
+        !self.0.insert(position.clone())
+    }
+
+    /// Check if a position has been visited, without marking it.
+    pub fn has_visited(&self, position: &T) -> bool {
+        self.0.contains(position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq + Clone> Default for RepeatedPositionDetector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // impl<S: Eq + Hash, T: Eq + Hash + Loopy<S>> Hash for LoopyTracker<S, T> {
-//     fn hash<H: std::hash::Hasher>(&self, hasher: &mut H) {
+//     fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
 //         for item in self.visited.iter() {
 //             item.hash(hasher);
 //         }
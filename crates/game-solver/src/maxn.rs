@@ -0,0 +1,164 @@
+//! Games with more than two players.
+//!
+//! With more than two players, one player's loss isn't necessarily another's gain,
+//! so a position can't be scored by a single number like [`crate::solve`] does.
+//! Instead, [`maxn`] scores every position with a vector holding a score for every player,
+//! and the player to move picks the move that maximizes their own score.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    game::{upper_bound, Game, GameState},
+    player::Player,
+    GameSolveError,
+};
+
+/// Scores `game` for every player, indexed by [`Player::idx`].
+///
+/// The returned vector has [`Player::num_players`] scores. Like in [`crate::solve`], the winner of a game
+/// scores the [`upper_bound`] of `game`, less the moves made before the win,
+/// and every other player scores the negation of that, so earlier wins (and later losses) score higher.
+/// Every player scores 0 in a tie.
+///
+/// A playable position without any moves is lost for the player to move,
+/// without any of the other players winning it.
+///
+/// If two moves are worth the same to the player to move, the first one is picked.
+pub fn maxn<T: Game>(game: &T) -> Result<Vec<isize>, GameSolveError<T>> {
+    let upper_bound = upper_bound(game).ok_or(GameSolveError::UnboundedGame)?;
+    maxn_search(game, upper_bound)
+}
+
+/// Scores `game` for every player, where `upper_bound` is the [`upper_bound`] of the position
+/// the search started from.
+fn maxn_search<T: Game>(game: &T, upper_bound: isize) -> Result<Vec<isize>, GameSolveError<T>> {
+    let players = T::Player::num_players();
+
+    if game.is_repeated_position() || game.is_draw() || game.is_repetition_draw() {
+        return Ok(vec![0; players]);
+    }
+
+    match game.state() {
+        GameState::Playable => (),
+        GameState::Tie => return Ok(vec![0; players]),
+        GameState::Win(winning_player) => {
+            let max_score = upper_bound - game.move_count() as isize + 1;
+            let mut scores = vec![-max_score; players];
+            scores[winning_player.idx()] = max_score;
+            return Ok(scores);
+        }
+    }
+
+    let player = game.player().idx();
+    let mut best: Option<Vec<isize>> = None;
+
+    for m in game.possible_moves() {
+        let mut board = game.clone();
+        board
+            .make_move(&m)
+            .map_err(|err| GameSolveError::MoveError(err))?;
+
+        let scores = maxn_search(&board, upper_bound)?;
+        if best
+            .as_ref()
+            .map_or(true, |best| scores[player] > best[player])
+        {
+            best = Some(scores);
+        }
+    }
+
+    match best {
+        Some(best) => Ok(best),
+        None => {
+            let mut scores = vec![0; players];
+            scores[player] = -(upper_bound - game.move_count() as isize + 1);
+            Ok(scores)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::player::NPlayerPartizanConst;
+
+    /// Three players take turns taking 1 or 2 from a pile,
+    /// and whoever takes the last of it wins.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct ThreeWayPile {
+        pile: usize,
+        move_count: usize,
+    }
+
+    impl Game for ThreeWayPile {
+        type Move = usize;
+        type Iter<'a> = core::iter::Filter<core::array::IntoIter<usize, 2>, fn(&usize) -> bool>;
+        type MoveError = Infallible;
+        type Player = NPlayerPartizanConst<3>;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.move_count + self.pile)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.pile -= m;
+            self.move_count += 1;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            let takes: fn(&usize) -> bool = match self.pile {
+                0 => |_| false,
+                1 => |&m| m == 1,
+                _ => |_| true,
+            };
+            [1, 2].into_iter().filter(takes)
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if self.pile == 0 {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            NPlayerPartizanConst::new(self.move_count % 3)
+        }
+    }
+
+    fn winner(pile: usize) -> usize {
+        let scores = maxn(&ThreeWayPile {
+            pile,
+            move_count: 0,
+        })
+        .unwrap();
+
+        assert_eq!(scores.len(), 3);
+        let winners: Vec<_> = (0..3).filter(|&player| scores[player] > 0).collect();
+        assert_eq!(winners.len(), 1, "{scores:?} doesn't have one winner");
+        winners[0]
+    }
+
+    #[test]
+    fn takes_the_win_when_it_can() {
+        assert_eq!(winner(1), 0);
+        assert_eq!(winner(2), 0);
+    }
+
+    #[test]
+    fn losers_play_for_the_latest_loss() {
+        // both moves hand the second player the win.
+        assert_eq!(winner(3), 1);
+        // taking 2 loses to the second player right away,
+        // so the first player takes 1 and loses to the third player a move later.
+        assert_eq!(winner(4), 2);
+    }
+}
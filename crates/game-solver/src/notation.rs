@@ -0,0 +1,295 @@
+//! Loading and saving positions in a game's standard notation,
+//! e.g. FEN for chess or SGF for go.
+
+use alloc::string::String;
+use core::{error::Error, hash::Hash};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, vec::Vec};
+
+use thiserror::Error;
+
+use crate::{
+    game::Game, player::TwoPlayer, solve, stats::Stats, transposition::TranspositionTable,
+    GameSolveError,
+};
+
+/// A game whose positions can be written in (and read from) some textual notation.
+pub trait GameParser: Game + Sized {
+    type ParseError: Error;
+
+    /// Reads a position from its notation.
+    fn from_notation(s: &str) -> Result<Self, Self::ParseError>;
+
+    /// Writes this position in the same notation [`GameParser::from_notation`] reads.
+    fn to_notation(&self) -> String;
+}
+
+#[derive(Error, Debug)]
+pub enum ParseAndSolveError<T: GameParser> {
+    #[error("could not parse the position")]
+    ParseError(T::ParseError),
+    #[error("could not solve the position")]
+    SolveError(GameSolveError<T>),
+}
+
+/// Parses a position from its notation, then solves it with [`solve`].
+pub fn parse_and_solve<T: GameParser<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    notation: &str,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    stats: Option<&Stats<T::Player>>,
+) -> Result<isize, ParseAndSolveError<T>> {
+    let game = T::from_notation(notation).map_err(ParseAndSolveError::ParseError)?;
+
+    solve(&game, transposition_table, stats).map_err(ParseAndSolveError::SolveError)
+}
+
+/// Parses a position from its notation, then solves it with a fresh transposition table,
+/// returning the parsed position alongside its score.
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub fn solve_from_notation<T: GameParser<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    notation: &str,
+) -> Result<(T, isize), ParseAndSolveError<T>> {
+    let game = T::from_notation(notation).map_err(ParseAndSolveError::ParseError)?;
+    let score = solve(&game, &mut HashMap::new(), None).map_err(ParseAndSolveError::SolveError)?;
+
+    Ok((game, score))
+}
+
+/// Parses a position from its notation, then scores each of its moves with [`crate::move_scores`],
+/// sharing a fresh transposition table between them.
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub fn move_scores_from_notation<T: GameParser<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    notation: &str,
+) -> Result<Vec<(T::Move, isize)>, ParseAndSolveError<T>> {
+    let game = T::from_notation(notation).map_err(ParseAndSolveError::ParseError)?;
+
+    crate::move_scores(&game, &mut HashMap::new(), None)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ParseAndSolveError::SolveError)
+}
+
+/// Writes a line of play (moves made from a starting position) in some human-readable format,
+/// e.g. to print a principal variation after solving.
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub trait GameWriter<T: Game> {
+    /// Writes `moves`, made in order from `game`.
+    fn write_game<W: std::io::Write>(
+        &self,
+        game: &T,
+        moves: &[T::Move],
+        output: &mut W,
+    ) -> std::io::Result<()>;
+}
+
+/// Writes moves on one line, separated by spaces, with their [`Debug`](core::fmt::Debug) format.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DebugGameWriter;
+
+#[cfg(feature = "std")]
+impl<T: Game> GameWriter<T> for DebugGameWriter
+where
+    T::Move: core::fmt::Debug,
+{
+    fn write_game<W: std::io::Write>(
+        &self,
+        _game: &T,
+        moves: &[T::Move],
+        output: &mut W,
+    ) -> std::io::Result<()> {
+        writeln!(
+            output,
+            "{}",
+            moves
+                .iter()
+                .map(|m| format!("{m:?}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
+/// Writes moves on one line, separated by spaces, with their [`Display`](core::fmt::Display) format.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayGameWriter;
+
+#[cfg(feature = "std")]
+impl<T: Game> GameWriter<T> for DisplayGameWriter
+where
+    T::Move: core::fmt::Display,
+{
+    fn write_game<W: std::io::Write>(
+        &self,
+        _game: &T,
+        moves: &[T::Move],
+        output: &mut W,
+    ) -> std::io::Result<()> {
+        writeln!(
+            output,
+            "{}",
+            moves
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
+/// Writes one move per line, numbered like PGN: the first move is numbered
+/// one more than the starting position's [`Game::move_count`], e.g. `1. 0-2`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PgnStyleWriter;
+
+#[cfg(feature = "std")]
+impl<T: Game> GameWriter<T> for PgnStyleWriter
+where
+    T::Move: core::fmt::Display,
+{
+    fn write_game<W: std::io::Write>(
+        &self,
+        game: &T,
+        moves: &[T::Move],
+        output: &mut W,
+    ) -> std::io::Result<()> {
+        for (i, m) in moves.iter().enumerate() {
+            writeln!(output, "{}. {m}", game.move_count() + i + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The principal variation of `game`: the line of best play for both players.
+///
+/// Every move is picked by solving the positions it leads to with [`solve`],
+/// so `transposition_table` should be the one that solved `game`, to reuse its work.
+/// (Scores alone aren't enough, since null window searches mostly store bounds.)
+/// Ties between moves go to the first one, and loopy games are cut off at their move bound.
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub fn principal_variation<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+) -> Result<Vec<T::Move>, GameSolveError<T>> {
+    let mut line = Vec::new();
+    let mut game = game.clone();
+    let max_length = game
+        .max_moves()
+        .unwrap_or(usize::MAX)
+        .saturating_sub(game.move_count());
+
+    while line.len() < max_length && !game.state().is_terminal() {
+        let mut best: Option<(isize, T::Move, T)> = None;
+
+        for m in game.possible_moves() {
+            let mut board = game.clone();
+            board.make_move(&m).map_err(GameSolveError::MoveError)?;
+
+            let score = -solve(&board, transposition_table, None)?;
+            if best.as_ref().map_or(true, |(best, _, _)| score > *best) {
+                best = Some((score, m, board));
+            }
+        }
+
+        let Some((_, m, board)) = best else {
+            break;
+        };
+
+        line.push(m);
+        game = board;
+    }
+
+    Ok(line)
+}
+
+/// Writes the [`principal_variation`] of `game` with `writer`.
+/// Errors from solving are reported as I/O errors of [`std::io::ErrorKind::Other`].
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub fn write_principal_variation<
+    T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash,
+    GW: GameWriter<T>,
+>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    writer: &GW,
+    output: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let line = principal_variation(game, transposition_table)
+        .map_err(|err| std::io::Error::other(err.to_string()))?;
+
+    writer.write_game(game, &line, output)
+}
+
+// (the tests use `HashMap`s as transposition tables, and need `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        solve,
+        tests::{Nim, Subtraction},
+    };
+
+    #[test]
+    fn game_writers() {
+        let write = |writer: &dyn Fn(&mut Vec<u8>) -> std::io::Result<()>| {
+            let mut output = Vec::new();
+            writer(&mut output).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        let subtraction = Subtraction::new(5);
+        let moves = [1, 2];
+        assert_eq!(
+            write(&|output| DisplayGameWriter.write_game(&subtraction, &moves, output)),
+            "1 2\n"
+        );
+        assert_eq!(
+            write(&|output| PgnStyleWriter.write_game(&subtraction, &moves, output)),
+            "1. 1\n2. 2\n"
+        );
+
+        let nim = Nim::new(vec![2, 3]);
+        let moves = [(0, 1), (1, 3)];
+        assert_eq!(
+            write(&|output| DebugGameWriter.write_game(&nim, &moves, output)),
+            "(0, 1) (1, 3)\n"
+        );
+    }
+
+    #[test]
+    fn principal_variations_are_best_play() {
+        let game = Subtraction::new(5);
+        let mut transposition_table = HashMap::new();
+        let score = solve(&game, &mut transposition_table, None).unwrap();
+        assert!(score > 0);
+
+        let line = principal_variation(&game, &mut transposition_table).unwrap();
+        let mut board = game.clone();
+        for m in &line {
+            board.make_move(m).unwrap();
+        }
+        assert!(board.state().is_terminal());
+        // the player to move wins, so they make the last move.
+        assert_eq!(line.len() % 2, 1);
+
+        let mut output = Vec::new();
+        write_principal_variation(
+            &game,
+            &mut transposition_table,
+            &PgnStyleWriter,
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap().lines().count(),
+            line.len()
+        );
+    }
+}
@@ -1,8 +1,14 @@
+use core::fmt::{self, Display, Formatter};
+
 /// Represents a player.
 pub trait Player: Sized + Eq {
-    /// The max player count.
+    /// The number of players in the game.
+    ///
+    /// This is an associated function rather than a method, since it must be the same
+    /// for every player (and every position) of a game: it's what per-player data,
+    /// like the score vectors of [`crate::maxn`], is sized by, and [`Player::idx`] is always below it.
     #[must_use]
-    fn count() -> usize;
+    fn num_players() -> usize;
     /// The current index of this player starting at 0.
     #[must_use]
     fn idx(&self) -> usize;
@@ -45,8 +51,22 @@ pub enum PartizanPlayer {
     Right,
 }
 
+impl PartizanPlayer {
+    /// The player to move after `move_count` moves, when players simply alternate,
+    /// starting with [`PartizanPlayer::Left`]. Most games can use this as their [`crate::game::Game::player`].
+    ///
+    /// (The other player is [`TwoPlayer::other`], and the index of a player is [`Player::idx`].)
+    pub const fn from_move_count(move_count: usize) -> Self {
+        if move_count % 2 == 0 {
+            Self::Left
+        } else {
+            Self::Right
+        }
+    }
+}
+
 impl Player for PartizanPlayer {
-    fn count() -> usize {
+    fn num_players() -> usize {
         2
     }
 
@@ -75,6 +95,15 @@ impl Player for PartizanPlayer {
 
 impl TwoPlayer for PartizanPlayer {}
 
+impl Display for PartizanPlayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+        }
+    }
+}
+
 /// Represents a player in a zero-sum (2-player) game,
 /// where the game is impartial. That is,
 /// the only difference between players is who goes first,
@@ -100,7 +129,7 @@ impl ImpartialPlayer {
 }
 
 impl Player for ImpartialPlayer {
-    fn count() -> usize {
+    fn num_players() -> usize {
         2
     }
 
@@ -129,8 +158,17 @@ impl Player for ImpartialPlayer {
 
 impl TwoPlayer for ImpartialPlayer {}
 
+impl Display for ImpartialPlayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Next => write!(f, "Next"),
+            Self::Previous => write!(f, "Previous"),
+        }
+    }
+}
+
 /// Represents a player in an N-player game.
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
 pub struct NPlayerPartizanConst<const N: usize>(usize);
 
 impl<const N: usize> NPlayerPartizanConst<N> {
@@ -146,7 +184,7 @@ impl<const N: usize> NPlayerPartizanConst<N> {
 }
 
 impl<const N: usize> Player for NPlayerPartizanConst<N> {
-    fn count() -> usize {
+    fn num_players() -> usize {
         N
     }
 
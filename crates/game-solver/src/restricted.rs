@@ -0,0 +1,347 @@
+//! Solving games where some moves are forbidden, e.g. puzzles that ask for a win under
+//! some constraint (such as only moving a certain piece).
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+#[cfg(feature = "rayon")]
+use std::sync::Arc;
+
+#[cfg(feature = "rayon")]
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    game::{Game, GameState},
+    player::TwoPlayer,
+    stats::Stats,
+    transposition::{ReplacementPolicy, Score, TranspositionTable},
+    GameSolveError,
+};
+
+/// Wraps a game, only allowing the moves of [`Game::possible_moves`] that `filter` accepts.
+///
+/// Every other method is delegated to the wrapped game, so the state of a position
+/// doesn't account for forbidden moves: a playable position where every move is forbidden
+/// is lost for the player to move. The filter is ignored when comparing and hashing positions.
+#[derive(Clone)]
+pub struct RestrictedGame<T: Game, F: Fn(&T, &T::Move) -> bool + Clone>(pub T, pub F);
+
+impl<T: Game, F: Fn(&T, &T::Move) -> bool + Clone> RestrictedGame<T, F> {
+    /// The wrapped game.
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Game + Debug, F: Fn(&T, &T::Move) -> bool + Clone> Debug for RestrictedGame<T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Game + PartialEq, F: Fn(&T, &T::Move) -> bool + Clone> PartialEq for RestrictedGame<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Game + Eq, F: Fn(&T, &T::Move) -> bool + Clone> Eq for RestrictedGame<T, F> {}
+
+impl<T: Game + Hash, F: Fn(&T, &T::Move) -> bool + Clone> Hash for RestrictedGame<T, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+/// The moves of a [`RestrictedGame`] that its filter accepts.
+pub struct RestrictedMoves<'a, T: Game + 'a, F> {
+    moves: T::Iter<'a>,
+    game: &'a T,
+    filter: &'a F,
+}
+
+impl<'a, T: Game, F: Fn(&T, &T::Move) -> bool> Iterator for RestrictedMoves<'a, T, F> {
+    type Item = T::Move;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (game, filter) = (self.game, self.filter);
+        self.moves.find(|m| filter(game, m))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.moves.size_hint().1)
+    }
+}
+
+impl<T: Game, F: Fn(&T, &T::Move) -> bool + Clone> Game for RestrictedGame<T, F> {
+    type Move = T::Move;
+    type Iter<'a> = RestrictedMoves<'a, T, F> where Self: 'a;
+    type MoveError = T::MoveError;
+    type Player = T::Player;
+
+    fn move_count(&self) -> usize {
+        self.0.move_count()
+    }
+
+    fn max_moves(&self) -> Option<usize> {
+        self.0.max_moves()
+    }
+
+    fn min_score(&self) -> Option<isize> {
+        self.0.min_score()
+    }
+
+    fn max_score(&self) -> Option<isize> {
+        self.0.max_score()
+    }
+
+    fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+        self.0.make_move(m)
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        RestrictedMoves {
+            moves: self.0.possible_moves(),
+            game: &self.0,
+            filter: &self.1,
+        }
+    }
+
+    fn canonical_moves(&self) -> Self::Iter<'_> {
+        RestrictedMoves {
+            moves: self.0.canonical_moves(),
+            game: &self.0,
+            filter: &self.1,
+        }
+    }
+
+    fn is_quiet(&self) -> bool {
+        self.0.is_quiet()
+    }
+
+    fn noisy_moves(&self) -> Self::Iter<'_> {
+        RestrictedMoves {
+            moves: self.0.noisy_moves(),
+            game: &self.0,
+            filter: &self.1,
+        }
+    }
+
+    fn is_winning_move(&self, m: &Self::Move) -> bool {
+        self.0.is_winning_move(m)
+    }
+
+    fn score_after_winning_move(&self, m: &Self::Move) -> Option<isize> {
+        self.0.score_after_winning_move(m)
+    }
+
+    fn move_to_index(&self, m: &Self::Move) -> Option<usize> {
+        self.0.move_to_index(m)
+    }
+
+    fn move_from_index(&self, index: usize) -> Option<Self::Move> {
+        self.0.move_from_index(index)
+    }
+
+    fn move_to_index_count(&self) -> Option<usize> {
+        self.0.move_to_index_count()
+    }
+
+    fn moves_equal(&self, a: &Self::Move, b: &Self::Move) -> bool
+    where
+        Self::Move: PartialEq,
+    {
+        self.0.moves_equal(a, b)
+    }
+
+    fn is_draw(&self) -> bool {
+        self.0.is_draw()
+    }
+
+    fn is_repeated_position(&self) -> bool {
+        self.0.is_repeated_position()
+    }
+
+    fn repetition_count(&self) -> usize {
+        self.0.repetition_count()
+    }
+
+    fn is_repetition_draw(&self) -> bool {
+        self.0.is_repetition_draw()
+    }
+
+    fn evaluate(&self, maximizer: Self::Player) -> Option<isize> {
+        self.0.evaluate(maximizer)
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        self.0.state()
+    }
+
+    fn player(&self) -> Self::Player {
+        self.0.player()
+    }
+}
+
+/// Stores the positions of a [`RestrictedGame`] in a table of the unrestricted game.
+struct RestrictedTable<'a, T: Game + Eq + Hash>(&'a mut dyn TranspositionTable<T>);
+
+impl<T: Game + Eq + Hash, F: Fn(&T, &T::Move) -> bool + Clone>
+    TranspositionTable<RestrictedGame<T, F>> for RestrictedTable<'_, T>
+{
+    fn get(&self, board: &RestrictedGame<T, F>) -> Option<Score> {
+        self.0.get(&board.0)
+    }
+
+    fn insert(&mut self, board: RestrictedGame<T, F>, score: Score) {
+        self.0.insert(board.0, score);
+    }
+
+    fn insert_with_depth(&mut self, board: RestrictedGame<T, F>, score: Score, depth: u8) {
+        self.0.insert_with_depth(board.0, score, depth);
+    }
+
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        self.0.replacement_policy()
+    }
+
+    fn has(&self, board: &RestrictedGame<T, F>) -> bool {
+        self.0.has(&board.0)
+    }
+}
+
+fn unrestricted_error<T: Game, F: Fn(&T, &T::Move) -> bool + Clone>(
+    err: GameSolveError<RestrictedGame<T, F>>,
+) -> GameSolveError<T> {
+    match err {
+        GameSolveError::MoveError(err) => GameSolveError::MoveError(err),
+        GameSolveError::Cancelled => GameSolveError::Cancelled,
+        GameSolveError::UnboundedGame => GameSolveError::UnboundedGame,
+    }
+}
+
+/// Solves `game` like [`crate::solve`], where only moves that `filter` accepts can be played
+/// (by either player).
+///
+/// Since forbidding moves changes the scores of positions, `transposition_table`
+/// should only be shared between solves with the same filter.
+pub fn solve_with_move_restriction<
+    T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash,
+    F: Fn(&T, &T::Move) -> bool + Clone,
+>(
+    game: &T,
+    filter: F,
+    transposition_table: &mut dyn TranspositionTable<T>,
+) -> Result<isize, GameSolveError<T>> {
+    crate::solve(
+        &RestrictedGame(game.clone(), filter),
+        &mut RestrictedTable(transposition_table),
+        None,
+    )
+    .map_err(unrestricted_error)
+}
+
+/// Scores every move of `game` that `filter` accepts, like [`crate::move_scores`],
+/// where only moves that `filter` accepts can be played afterwards.
+///
+/// `transposition_table` should only be shared between solves with the same filter.
+pub fn move_scores_restricted<
+    'a,
+    T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash + 'a,
+    F: Fn(&T, &T::Move) -> bool + Clone + 'a,
+>(
+    game: &T,
+    filter: F,
+    transposition_table: &'a mut dyn TranspositionTable<T>,
+    stats: Option<&'a Stats<T::Player>>,
+) -> impl Iterator<Item = Result<(T::Move, isize), GameSolveError<T>>> + 'a {
+    let game = RestrictedGame(game.clone(), filter);
+    let mut transposition_table = RestrictedTable(transposition_table);
+
+    let moves = game.possible_moves().collect::<Vec<_>>();
+    moves.into_iter().map(move |m| {
+        let mut board = game.clone();
+        board.make_move(&m).map_err(GameSolveError::MoveError)?;
+
+        let score =
+            crate::solve(&board, &mut transposition_table, stats).map_err(unrestricted_error)?;
+        Ok((m, -score))
+    })
+}
+
+/// Parallelized version of [`move_scores_restricted`], like [`crate::par_move_scores`].
+/// This requires the `rayon` feature to be enabled.
+#[cfg(feature = "rayon")]
+pub async fn par_move_scores_restricted<
+    T: Game<Player = impl TwoPlayer + Sync + Send + 'static> + Eq + Hash + Sync + Send + 'static,
+    F: Fn(&T, &T::Move) -> bool + Clone + Sync + Send + 'static,
+>(
+    game: &T,
+    filter: F,
+    stats: Option<Arc<Stats<T::Player>>>,
+    cancellation_token: Option<CancellationToken>,
+) -> crate::CollectedMoves<T>
+where
+    T::Move: Sync + Send,
+    T::MoveError: Sync + Send,
+{
+    crate::par_move_scores(
+        &RestrictedGame(game.clone(), filter),
+        stats,
+        cancellation_token,
+    )
+    .await
+    .into_iter()
+    .map(|result| result.map_err(unrestricted_error))
+    .collect()
+}
+
+// (the tests use the Nim test game, which needs `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::tests::Nim;
+
+    /// Only taking one object at a time, so the first player wins if there's an odd amount of objects.
+    fn take_one(_: &Nim, &(_, amount): &(usize, usize)) -> bool {
+        amount == 1
+    }
+
+    #[test]
+    fn restricted_moves() {
+        for heaps in [vec![2, 3], vec![2, 2], vec![1, 2, 3], vec![3, 4]] {
+            let nim = Nim::new(heaps.clone());
+            let score = solve_with_move_restriction(&nim, take_one, &mut HashMap::new()).unwrap();
+            assert_eq!(score > 0, heaps.iter().sum::<usize>() % 2 == 1, "{heaps:?}");
+
+            let scores = move_scores_restricted(&nim, take_one, &mut HashMap::new(), None)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert!(scores.iter().all(|(m, _)| take_one(&nim, m)));
+            assert_eq!(scores.len(), heaps.len());
+            assert_eq!(scores.iter().map(|(_, score)| *score).max(), Some(score));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn par_restricted_moves() {
+        let nim = Nim::new(vec![2, 3]);
+        let mut sequential = move_scores_restricted(&nim, take_one, &mut HashMap::new(), None)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let mut parallel = par_move_scores_restricted(&nim, take_one, None, None)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        sequential.sort();
+        parallel.sort();
+        assert_eq!(parallel, sequential);
+    }
+}
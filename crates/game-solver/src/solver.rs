@@ -0,0 +1,233 @@
+//! A stateful solver, for analyzing a game as it's being played.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt::Debug, hash::Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+#[cfg(feature = "serde")]
+use crate::transposition::Score;
+use crate::{
+    config::SolverConfig, game::Game, player::TwoPlayer, solve_with_config, stats::Stats,
+    transposition::CloneableTranspositionTable, GameSolveError,
+};
+
+/// Keeps track of a game as moves are made, alongside a transposition table
+/// that persists between positions.
+///
+/// This is useful for agents that receive moves one at a time (e.g. a game server):
+/// the analysis of earlier positions already covers much of the later ones,
+/// so there's no need to rebuild the transposition table after every move.
+///
+/// Cloning a solver clones its transposition table, e.g. to explore a line of play
+/// without affecting the original.
+#[derive(Clone, Debug)]
+pub struct Solver<T: Game + Eq + Hash + 'static> {
+    game: T,
+    /// Every position before the current one, to be restored by [`Solver::undo_move`].
+    history: Vec<T>,
+    transposition_table: Box<dyn CloneableTranspositionTable<T>>,
+    config: SolverConfig,
+}
+
+impl<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash + Debug + 'static> Solver<T> {
+    /// Creates a solver that uses a [`HashMap`] as its transposition table.
+    #[cfg(feature = "std")]
+    pub fn new(game: T) -> Self {
+        Self::with_transposition_table(game, Box::new(HashMap::new()))
+    }
+
+    /// Creates a solver that uses the given transposition table.
+    pub fn with_transposition_table(
+        game: T,
+        transposition_table: Box<dyn CloneableTranspositionTable<T>>,
+    ) -> Self {
+        Self {
+            game,
+            history: Vec::new(),
+            transposition_table,
+            config: SolverConfig::default(),
+        }
+    }
+
+    pub fn with_config(mut self, config: SolverConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The current position.
+    pub fn game(&self) -> &T {
+        &self.game
+    }
+
+    pub fn config(&self) -> &SolverConfig {
+        &self.config
+    }
+
+    /// Makes a move on the current position.
+    /// If the move fails, the position is left unchanged.
+    pub fn make_move(&mut self, m: &T::Move) -> Result<(), T::MoveError> {
+        let mut board = self.game.clone();
+        board.make_move(m)?;
+        self.history.push(core::mem::replace(&mut self.game, board));
+        Ok(())
+    }
+
+    /// Restores the position before the last move,
+    /// returning the position that was undone (or None if no moves were made).
+    pub fn undo_move(&mut self) -> Option<T> {
+        let previous = self.history.pop()?;
+        Some(core::mem::replace(&mut self.game, previous))
+    }
+
+    /// Captures the current position, its history, the configuration,
+    /// and every entry of the transposition table, to resume solving later.
+    ///
+    /// # Panics
+    ///
+    /// If the transposition table doesn't support
+    /// [iteration](crate::transposition::TranspositionTable::iter).
+    #[cfg(feature = "serde")]
+    pub fn snapshot(&self) -> SolverSnapshot<T> {
+        SolverSnapshot {
+            game: self.game.clone(),
+            history: self.history.clone(),
+            transposition_table_entries: self.transposition_table.iter().collect(),
+            config: self.config.clone(),
+        }
+    }
+
+    /// Resumes a solver from a [`SolverSnapshot`],
+    /// with a [`HashMap`] of the snapshot's entries as its transposition table.
+    #[cfg(feature = "serde")]
+    pub fn from_snapshot(snapshot: SolverSnapshot<T>) -> Self {
+        let transposition_table: HashMap<T, Score> =
+            snapshot.transposition_table_entries.into_iter().collect();
+
+        Self {
+            game: snapshot.game,
+            history: snapshot.history,
+            transposition_table: Box::new(transposition_table),
+            config: snapshot.config,
+        }
+    }
+
+    /// Solves the current position. See [`crate::solve`].
+    pub fn solve(&mut self, stats: Option<&Stats<T::Player>>) -> Result<isize, GameSolveError<T>> {
+        solve_with_config(
+            &self.game,
+            &mut self.transposition_table,
+            &self.config,
+            stats,
+        )
+    }
+}
+
+/// The state of a [`Solver`], which can be written to disk (as JSON)
+/// so a long-running solve can be resumed after it's interrupted.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SolverSnapshot<T> {
+    pub game: T,
+    /// Every position before `game`, oldest first.
+    pub history: Vec<T>,
+    pub transposition_table_entries: Vec<(T, Score)>,
+    pub config: SolverConfig,
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> SolverSnapshot<T> {
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    pub fn restore(path: &Path) -> io::Result<Self> {
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+}
+
+// (the tests use the Nim test game, which needs `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{solve, tests::Nim};
+
+    #[test]
+    fn solver_follows_moves() {
+        let nim = Nim::new(vec![2, 3, 4]);
+        let mut solver = Solver::new(nim.clone());
+
+        let score = solver.solve(None).unwrap();
+        assert_eq!(score, solve(&nim, &mut HashMap::new(), None).unwrap());
+
+        let m = nim.possible_moves().next().unwrap();
+        solver.make_move(&m).unwrap();
+
+        let mut after = nim.clone();
+        after.make_move(&m).unwrap();
+        assert_eq!(solver.game(), &after);
+        assert_eq!(
+            solver.solve(None).unwrap(),
+            solve(&after, &mut HashMap::new(), None).unwrap()
+        );
+
+        assert_eq!(solver.undo_move(), Some(after));
+        assert_eq!(solver.game(), &nim);
+        assert_eq!(solver.solve(None).unwrap(), score);
+        assert_eq!(solver.undo_move(), None);
+    }
+
+    #[test]
+    fn cloned_solvers_are_independent() {
+        let nim = Nim::new(vec![2, 3, 4]);
+        let mut solver = Solver::new(nim.clone());
+        let score = solver.solve(None).unwrap();
+
+        let mut explored = solver.clone();
+        let m = nim.possible_moves().next().unwrap();
+        explored.make_move(&m).unwrap();
+        explored.solve(None).unwrap();
+
+        assert_eq!(solver.game(), &nim);
+        assert_eq!(solver.solve(None).unwrap(), score);
+        assert!(format!("{solver:?}").contains("Solver"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn solver_snapshots_restore() {
+        let nim = Nim::new(vec![2, 3, 4]);
+        let mut solver = Solver::new(nim.clone());
+        solver
+            .make_move(&nim.possible_moves().next().unwrap())
+            .unwrap();
+        let score = solver.solve(None).unwrap();
+
+        let snapshot = solver.snapshot();
+        assert!(!snapshot.transposition_table_entries.is_empty());
+
+        let path = std::env::temp_dir().join(format!("nim-snapshot-{}.json", std::process::id()));
+        snapshot.save(&path).unwrap();
+        let restored = SolverSnapshot::<Nim>::restore(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut restored = Solver::from_snapshot(restored);
+        assert_eq!(restored.game(), solver.game());
+
+        // the restored table already has the exact score of the position
+        let stats = Stats::new(nim.player(), nim.move_count());
+        assert_eq!(restored.solve(Some(&stats)).unwrap(), score);
+        assert!(stats.cache_hits.into_inner() > 0);
+
+        assert_eq!(restored.undo_move(), Some(solver.game().clone()));
+        assert_eq!(restored.game(), &nim);
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicU64, AtomicUsize};
+use core::sync::atomic::{AtomicU64, AtomicUsize};
 
 use crate::player::Player;
 
@@ -25,7 +25,28 @@ pub struct Stats<P: Player> {
     pub max_depth: AtomicUsize,
     pub cache_hits: AtomicU64,
     pub pruning_cutoffs: AtomicU64,
+    /// Moves whose null window search failed high inside of the window,
+    /// and had to be searched again with the full window
+    /// (see [principal variation search](https://www.chessprogramming.org/Principal_Variation_Search)).
+    pub researches: AtomicU64,
     pub terminal_ends: TerminalEnds,
     pub original_player: P,
     pub original_move_count: usize,
 }
+
+impl<P: Player> Stats<P> {
+    /// Statistics with every counter at zero, for a search starting from a position
+    /// with `original_player` to move, after `original_move_count` moves.
+    pub fn new(original_player: P, original_move_count: usize) -> Self {
+        Self {
+            states_explored: AtomicU64::new(0),
+            max_depth: AtomicUsize::new(0),
+            cache_hits: AtomicU64::new(0),
+            pruning_cutoffs: AtomicU64::new(0),
+            researches: AtomicU64::new(0),
+            terminal_ends: TerminalEnds::default(),
+            original_player,
+            original_move_count,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Games with chance, where some positions are decided by a random outcome
+//! (e.g. a dice roll, or a card draw) instead of by a player.
+//!
+//! Since no player controls chance, these games can't be solved exactly like [`crate::solve`] does.
+//! Instead, [`expectimax`] scores chance positions by the average of their outcomes,
+//! weighted by how likely each one is.
+//!
+//! Learn more: <https://en.wikipedia.org/wiki/Expectiminimax>
+
+use crate::{
+    game::{Game, GameState},
+    player::TwoPlayer,
+    GameSolveError,
+};
+
+/// A game where some positions are chance nodes: rather than a player moving,
+/// one of several outcomes happens at random.
+pub trait StochasticGame: Game {
+    /// What chance decided, e.g. the value of a die.
+    type Outcome;
+
+    /// Every outcome chance can decide in this position, with the probability of it happening
+    /// and the position it leads to.
+    ///
+    /// This must be empty if a player moves in this position instead,
+    /// and the probabilities of a chance node's outcomes should add up to 1.
+    fn chance_outcomes(&self) -> impl Iterator<Item = (Self::Outcome, f64, Self)>;
+}
+
+/// Scores `game` for the player to move, searching `depth` plies (moves or chance outcomes) ahead.
+///
+/// Unlike [`crate::solve`], scores don't depend on how long the game takes:
+/// a win is worth `1.0`, a loss `-1.0`, and a tie `0.0`, so the score of a position
+/// is the expected result of the game under best play.
+/// Positions past the search depth are guessed to be worth `0.0`.
+pub fn expectimax<T: StochasticGame<Player = impl TwoPlayer>>(
+    game: &T,
+    depth: usize,
+) -> Result<f64, GameSolveError<T>> {
+    if game.is_repeated_position() || game.is_draw() || game.is_repetition_draw() {
+        return Ok(0.0);
+    }
+
+    match game.state() {
+        GameState::Playable => (),
+        GameState::Tie => return Ok(0.0),
+        GameState::Win(winning_player) => {
+            return Ok(if winning_player == game.player() {
+                1.0
+            } else {
+                -1.0
+            })
+        }
+    }
+
+    if depth == 0 {
+        return Ok(0.0);
+    }
+
+    // chance can hand the next move to either player, so every child score
+    // is turned to the perspective of this position's player.
+    let score_for = |board: &T, score: f64| {
+        if board.player() == game.player() {
+            score
+        } else {
+            -score
+        }
+    };
+
+    let mut outcomes = game.chance_outcomes().peekable();
+    if outcomes.peek().is_some() {
+        let mut expected = 0.0;
+        for (_, probability, board) in outcomes {
+            expected += probability * score_for(&board, expectimax(&board, depth - 1)?);
+        }
+
+        return Ok(expected);
+    }
+
+    let mut best = None;
+    for m in game.possible_moves() {
+        let mut board = game.clone();
+        board
+            .make_move(&m)
+            .map_err(|err| GameSolveError::MoveError(err))?;
+
+        let score = score_for(&board, expectimax(&board, depth - 1)?);
+        if best.map_or(true, |best| score > best) {
+            best = Some(score);
+        }
+    }
+
+    // a playable game without any moves is lost for the player to move (like in [`crate::solve`]).
+    Ok(best.unwrap_or(-1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::player::{PartizanPlayer, Player};
+
+    /// Players take turns rolling a die and then choosing to add 1 or 2 to the roll.
+    /// Whoever brings the running total to at least the target with their move wins.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct DiceRace {
+        total: usize,
+        target: usize,
+        move_count: usize,
+        /// Whether the player to move has rolled yet.
+        rolled: bool,
+    }
+
+    impl Game for DiceRace {
+        type Move = usize;
+        type Iter<'a> = core::array::IntoIter<usize, 2>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            self.move_count
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(self.target)
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.total += m;
+            self.move_count += 1;
+            self.rolled = false;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            [1, 2].into_iter()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            if !self.rolled && self.total >= self.target {
+                GameState::Win(self.player().previous())
+            } else {
+                GameState::Playable
+            }
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::from_move_count(self.move_count)
+        }
+    }
+
+    impl StochasticGame for DiceRace {
+        type Outcome = usize;
+
+        fn chance_outcomes(&self) -> impl Iterator<Item = (Self::Outcome, f64, Self)> {
+            let rolled = self.rolled;
+            (1..=6).filter(move |_| !rolled).map(move |roll| {
+                let mut board = self.clone();
+                board.total += roll;
+                board.rolled = true;
+                (roll, 1.0 / 6.0, board)
+            })
+        }
+    }
+
+    fn race(total: usize, target: usize) -> DiceRace {
+        DiceRace {
+            total,
+            target,
+            move_count: 0,
+            rolled: false,
+        }
+    }
+
+    fn assert_score(score: f64, expected: f64) {
+        assert!((score - expected).abs() < 1e-9, "{score} isn't {expected}");
+    }
+
+    #[test]
+    fn certain_wins_score_one() {
+        // any roll, plus either move, reaches the target.
+        assert_score(expectimax(&race(0, 3), 2).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn chance_nodes_average_their_outcomes() {
+        // rolling a 1 or a 2 can't reach the target of 5, and leaves the opponent close enough
+        // to win with any roll. every other roll reaches the target by moving 2.
+        assert_score(expectimax(&race(0, 5), 4).unwrap(), 4.0 / 6.0 - 2.0 / 6.0);
+    }
+
+    #[test]
+    fn out_of_depth_positions_are_guessed_as_ties() {
+        assert_eq!(expectimax(&race(0, 3), 0).unwrap(), 0.0);
+        // the roll alone is searched, but not the move after it.
+        assert_eq!(expectimax(&race(0, 3), 1).unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Aggregate statistics over every position of a game, as game-theoretic analyses usually report them.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+};
+
+use crate::{
+    game::{score_to_outcome, Game, GameScoreOutcome},
+    player::TwoPlayer,
+    solve_all_positions,
+    transposition::TranspositionTable,
+    GameSolveError,
+};
+
+/// How the positions reachable from some game end under perfect play, as found by [`game_summary`].
+///
+/// Players are counted by their [`Player::idx`](crate::player::Player::idx): for partizan games, player one is `Left`,
+/// while for impartial games, player one is whoever moves next in each position,
+/// so its wins are the N-positions and player two's wins are the P-positions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameSummary {
+    pub total_positions: usize,
+    pub player_one_wins: usize,
+    pub player_two_wins: usize,
+    pub ties: usize,
+    /// The amount of positions won by the player to move, indexed by the moves left until the win.
+    pub win_depth_histogram: Vec<usize>,
+    /// The amount of positions lost by the player to move, indexed by the moves left until the loss.
+    pub loss_depth_histogram: Vec<usize>,
+}
+
+impl GameSummary {
+    fn record(&mut self, winner: Option<usize>, outcome: GameScoreOutcome) {
+        self.total_positions += 1;
+
+        match winner {
+            Some(0) => self.player_one_wins += 1,
+            Some(_) => self.player_two_wins += 1,
+            None => self.ties += 1,
+        }
+
+        let histogram = match outcome {
+            GameScoreOutcome::Win(_) => &mut self.win_depth_histogram,
+            GameScoreOutcome::Loss(_) => &mut self.loss_depth_histogram,
+            GameScoreOutcome::Tie => return,
+        };
+
+        let depth = outcome.moves_remaining().unwrap();
+        if histogram.len() <= depth {
+            histogram.resize(depth + 1, 0);
+        }
+        histogram[depth] += 1;
+    }
+}
+
+impl Display for GameSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<14}  {:>10}", "positions", self.total_positions)?;
+        writeln!(f, "{:<14}  {:>10}", "player 1 wins", self.player_one_wins)?;
+        writeln!(f, "{:<14}  {:>10}", "player 2 wins", self.player_two_wins)?;
+        writeln!(f, "{:<14}  {:>10}", "ties", self.ties)?;
+
+        writeln!(f)?;
+        writeln!(f, "{:<10}  {:>10}  {:>10}", "moves left", "wins", "losses")?;
+
+        let depths = self
+            .win_depth_histogram
+            .len()
+            .max(self.loss_depth_histogram.len());
+        for depth in 0..depths {
+            writeln!(
+                f,
+                "{:<10}  {:>10}  {:>10}",
+                depth,
+                self.win_depth_histogram
+                    .get(depth)
+                    .copied()
+                    .unwrap_or_default(),
+                self.loss_depth_histogram
+                    .get(depth)
+                    .copied()
+                    .unwrap_or_default()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Solves every position reachable from `initial` (with [`solve_all_positions`]),
+/// and summarizes who wins them, and how quickly.
+///
+/// **Warning**: like [`solve_all_positions`], this holds every reachable position in memory.
+pub fn game_summary<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    initial: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+) -> Result<GameSummary, GameSolveError<T>> {
+    let mut summary = GameSummary::default();
+
+    for (game, score) in solve_all_positions(initial, transposition_table, None)? {
+        let outcome = score_to_outcome(&game, score).ok_or(GameSolveError::UnboundedGame)?;
+
+        let winner = match outcome {
+            GameScoreOutcome::Win(_) => Some(game.player().idx()),
+            GameScoreOutcome::Loss(_) => Some(game.player().other().idx()),
+            GameScoreOutcome::Tie => None,
+        };
+
+        summary.record(winner, outcome);
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::tests::Nim;
+
+    #[test]
+    fn game_summaries() {
+        let nim = Nim::new(vec![1, 2, 3]);
+        let summary = game_summary(&nim, &mut HashMap::new()).unwrap();
+        let positions = solve_all_positions(&nim, &mut HashMap::new(), None).unwrap();
+
+        // the player to move loses exactly when the heaps XOR to 0.
+        let losing = positions.keys().filter(|nim| nim.is_lost()).count();
+
+        assert_eq!(summary.total_positions, positions.len());
+        assert_eq!(summary.player_two_wins, losing);
+        assert_eq!(summary.player_one_wins, positions.len() - losing);
+        assert_eq!(summary.ties, 0);
+        assert_eq!(
+            summary.win_depth_histogram.iter().sum::<usize>(),
+            summary.player_one_wins
+        );
+        assert_eq!(summary.loss_depth_histogram.iter().sum::<usize>(), losing);
+
+        // only the empty positions (one per move count they're reached at) are lost without any moves left.
+        let empty = positions
+            .keys()
+            .filter(|nim| nim.heaps.iter().all(|&heap| heap == 0))
+            .count();
+        assert_eq!(summary.loss_depth_histogram[0], empty);
+        assert!(summary.to_string().starts_with("positions"));
+    }
+}
@@ -7,13 +7,19 @@ use std::sync::Arc;
 
 use crate::game::Game;
 
+use alloc::{boxed::Box, collections::BTreeMap};
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+#[cfg(feature = "std")]
 use std::{
     collections::HashMap,
-    hash::{BuildHasher, Hash},
+    hash::{DefaultHasher, Hasher},
 };
 
 /// A score in a transposition table.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Score {
     /// The lower bound of the score.
     /// This generally doesn't bring too much benefit,
@@ -22,6 +28,83 @@ pub enum Score {
     /// The upper bound of the score,
     /// which helps get rid of many useless branches.
     UpperBound(isize),
+    /// The exact score of the board.
+    /// This is only produced by soft-fail searches, when a score lands inside the search window.
+    Exact(isize),
+}
+
+impl Score {
+    /// Combines two scores of the same board (e.g. from different tables),
+    /// keeping whichever constrains the board the most.
+    ///
+    /// Exact scores win over bounds, and of two bounds of the same kind, the tighter one is kept.
+    /// A lower bound and an upper bound that meet make an exact score.
+    ///
+    /// A score can't hold both bounds otherwise, so the lower bound is kept,
+    /// and the upper bound is lost.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Exact(score), _) | (_, Self::Exact(score)) => Self::Exact(score),
+            (Self::LowerBound(a), Self::LowerBound(b)) => Self::LowerBound(a.max(b)),
+            (Self::UpperBound(a), Self::UpperBound(b)) => Self::UpperBound(a.min(b)),
+            (Self::LowerBound(min), Self::UpperBound(max))
+            | (Self::UpperBound(max), Self::LowerBound(min)) => {
+                if min == max {
+                    Self::Exact(min)
+                } else {
+                    Self::LowerBound(min)
+                }
+            }
+        }
+    }
+}
+
+/// Hints to the CPU that `data` is about to be read, for tables backed by plain memory
+/// (e.g. a `Vec` indexed by a Zobrist hash) to call from [`TranspositionTable::prefetch`].
+///
+/// Prefetching needs the `nightly` feature (and a nightly compiler); otherwise, this does nothing.
+#[inline(always)]
+pub fn prefetch_read<D>(data: *const D) {
+    #[cfg(feature = "nightly")]
+    // SAFETY: prefetching is only a hint, and never dereferences `data`, even if it's dangling.
+    unsafe {
+        // a locality of 3 keeps the data in every level of the cache.
+        core::intrinsics::prefetch_read_data(data, 3);
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    let _ = data;
+}
+
+/// Whether a transposition table overwrites the score a board already has when it's inserted again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReplacementPolicy {
+    /// Always store the newest score (e.g. what a `HashMap` does).
+    #[default]
+    AlwaysReplace,
+    /// Keep the first score a board was given.
+    NeverReplace,
+    /// Like [`ReplacementPolicy::PreferExact`], and also never replace a lower bound
+    /// with an upper bound.
+    PreferLowerBound,
+    /// Never replace an exact score with a bound.
+    PreferExact,
+}
+
+impl ReplacementPolicy {
+    /// Returns true if `new` should replace `existing`, the score the board has now (if any).
+    pub const fn should_replace(self, existing: Option<Score>, new: Score) -> bool {
+        match (self, existing, new) {
+            (_, None, _) | (Self::AlwaysReplace, _, _) => true,
+            (Self::NeverReplace, Some(_), _) => false,
+            (Self::PreferExact | Self::PreferLowerBound, Some(Score::Exact(_)), new) => {
+                matches!(new, Score::Exact(_))
+            }
+            (Self::PreferLowerBound, Some(Score::LowerBound(_)), Score::UpperBound(_)) => false,
+            (Self::PreferExact | Self::PreferLowerBound, Some(_), _) => true,
+        }
+    }
 }
 
 /// A memoization strategy for a perfect-information sequential game.
@@ -34,8 +117,134 @@ pub trait TranspositionTable<T: Eq + Hash + Game> {
 
     /// Returns true if the board is in the transposition table.
     fn has(&self, board: &T) -> bool;
+
+    /// Insert a board into the transposition table, found by a search `depth` moves deep.
+    ///
+    /// Searches that know how deep they searched (i.e. depth-limited ones) call this
+    /// instead of [`TranspositionTable::insert`]. By default, the depth is ignored
+    /// (see [`DepthAwareTranspositionTable`] for a table that uses it).
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        let _ = depth;
+        self.insert(board, score);
+    }
+
+    /// Hints that `board` is about to be looked up, so a table can start loading
+    /// its entry into the cache (e.g. with [`prefetch_read`]) while the search does other work.
+    ///
+    /// This is purely a performance hint: by default, it does nothing,
+    /// and prefetching the wrong entry is only slower, never incorrect.
+    fn prefetch(&self, board: &T) {
+        let _ = board;
+    }
+
+    /// How [`TranspositionTable::insert`] treats boards that are already in the table.
+    ///
+    /// By default, tables replace their scores (see [`ReplacementPolicyTable`]
+    /// to give any table another policy).
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        ReplacementPolicy::AlwaysReplace
+    }
+
+    /// Iterates over every entry in the transposition table, e.g. to export it.
+    ///
+    /// Boards are yielded by value, since not every table can hand out references to them.
+    ///
+    /// # Panics
+    ///
+    /// By default, this panics, as not every transposition table supports iteration.
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        panic!("iteration not supported")
+    }
+
+    /// Adds every entry of `other` (e.g. another table's [`TranspositionTable::iter`]),
+    /// which is useful for combining the tables of parallel workers.
+    ///
+    /// Boards already in this table keep the most constraining of both scores (see [`Score::merge`]).
+    fn merge(&mut self, other: impl IntoIterator<Item = (T, Score)>)
+    where
+        Self: Sized,
+    {
+        for (board, score) in other {
+            let score = match self.get(&board) {
+                Some(existing) => existing.merge(score),
+                None => score,
+            };
+            self.insert(board, score);
+        }
+    }
+}
+
+/// A transposition table that can be cloned and debugged behind a `Box<dyn _>`,
+/// which lets structs holding one (like [`crate::solver::Solver`]) derive both.
+///
+/// This is implemented for every `'static` table that implements `Clone` and `Debug`.
+pub trait CloneableTranspositionTable<T: Eq + Hash + Game>: TranspositionTable<T> + Debug {
+    /// Clones this table into a new box.
+    fn clone_box(&self) -> Box<dyn CloneableTranspositionTable<T>>;
+}
+
+impl<T: Eq + Hash + Game, Table: TranspositionTable<T> + Clone + Debug + 'static>
+    CloneableTranspositionTable<T> for Table
+{
+    fn clone_box(&self) -> Box<dyn CloneableTranspositionTable<T>> {
+        Box::new(self.clone())
+    }
+}
+
+/// A transposition table that can be sent to and shared between threads,
+/// as the parallel solvers (e.g. [`crate::par_solve_batch`]) do.
+///
+/// This is implemented for every table that is `Send` and `Sync`,
+/// such as a `TranspositionCache` behind an `Arc`, or a `HashMap` of `Send` and `Sync` games.
+/// The sequential solvers keep taking any `dyn TranspositionTable<T>`.
+pub trait SyncTranspositionTable<T: Eq + Hash + Game>: TranspositionTable<T> + Send + Sync {}
+
+impl<T: Eq + Hash + Game, Table: TranspositionTable<T> + Send + Sync + ?Sized>
+    SyncTranspositionTable<T> for Table
+{
+}
+
+impl<T: Eq + Hash + Game + 'static> Clone for Box<dyn CloneableTranspositionTable<T>> {
+    fn clone(&self) -> Self {
+        // (the box is a cloneable table itself, so this has to go through the inner table)
+        self.as_ref().clone_box()
+    }
 }
 
+impl<T: Eq + Hash + Game> TranspositionTable<T> for Box<dyn CloneableTranspositionTable<T>> {
+    fn get(&self, board: &T) -> Option<Score> {
+        self.as_ref().get(board)
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        self.as_mut().insert(board, score);
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        self.as_mut().insert_with_depth(board, score, depth);
+    }
+
+    fn prefetch(&self, board: &T) {
+        self.as_ref().prefetch(board);
+    }
+
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        self.as_ref().replacement_policy()
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.as_ref().has(board)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        self.as_ref().iter()
+    }
+}
+
+/// A [`HashMap`] is a transposition table as-is, so scores loaded from elsewhere
+/// (e.g. a database) can be passed to the solver directly, and read back out
+/// with the usual [`HashMap`] methods once it's done, without converting between types.
+#[cfg(feature = "std")]
 impl<K: Eq + Hash + Game, S: BuildHasher + Default> TranspositionTable<K> for HashMap<K, Score, S> {
     fn get(&self, board: &K) -> Option<Score> {
         self.get(board).copied()
@@ -48,6 +257,577 @@ impl<K: Eq + Hash + Game, S: BuildHasher + Default> TranspositionTable<K> for Ha
     fn has(&self, board: &K) -> bool {
         self.contains_key(board)
     }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, Score)> + '_> {
+        Box::new(HashMap::iter(self).map(|(board, score)| (board.clone(), *score)))
+    }
+
+    fn merge(&mut self, other: impl IntoIterator<Item = (K, Score)>) {
+        let other = other.into_iter();
+        self.reserve(other.size_hint().0);
+
+        for (board, score) in other {
+            self.entry(board)
+                .and_modify(|existing| *existing = existing.merge(score))
+                .or_insert(score);
+        }
+    }
+}
+
+/// Builds a [`HashMap`] transposition table out of its entries in one pass,
+/// e.g. to load a table saved with [`TranspositionTable::iter`].
+///
+/// To add entries to an existing table, use [`Extend::extend`],
+/// or [`TranspositionTable::merge`] to keep the most constraining score of boards in both.
+#[cfg(feature = "std")]
+pub fn transposition_table_from_iter<T: Eq + Hash + Game, I: IntoIterator<Item = (T, Score)>>(
+    iter: I,
+) -> HashMap<T, Score> {
+    HashMap::from_iter(iter)
+}
+
+/// Transposition table for `no_std` environments, where [`HashMap`](std::collections::HashMap)
+/// isn't available. This requires boards to be ordered rather than hashed.
+impl<K: Ord + Hash + Game> TranspositionTable<K> for BTreeMap<K, Score> {
+    fn get(&self, board: &K) -> Option<Score> {
+        self.get(board).copied()
+    }
+
+    fn insert(&mut self, board: K, score: Score) {
+        self.insert(board, score);
+    }
+
+    fn has(&self, board: &K) -> bool {
+        self.contains_key(board)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, Score)> + '_> {
+        Box::new(BTreeMap::iter(self).map(|(board, score)| (board.clone(), *score)))
+    }
+}
+
+/// Transposition table wrapper that stores every board by its [`Game::canonical_form`],
+/// so that symmetric positions share the same entry.
+///
+/// For games with many symmetries, this can drastically cut down the table size
+/// (e.g. up to 8x for Tic-Tac-Toe), at the cost of computing the canonical form on every access.
+#[derive(Debug, Clone)]
+pub struct SymmetryAwareTable<T: Eq + Hash + Game, Inner: TranspositionTable<T>> {
+    inner: Inner,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> SymmetryAwareTable<T, Inner> {
+    /// Wraps a transposition table.
+    pub fn new(inner: Inner) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped transposition table.
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T> + Default> Default
+    for SymmetryAwareTable<T, Inner>
+{
+    fn default() -> Self {
+        Self::new(Inner::default())
+    }
+}
+
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> TranspositionTable<T>
+    for SymmetryAwareTable<T, Inner>
+{
+    fn get(&self, board: &T) -> Option<Score> {
+        self.inner.get(&board.canonical_form())
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        self.inner.insert(board.canonical_form(), score);
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        self.inner
+            .insert_with_depth(board.canonical_form(), score, depth);
+    }
+
+    fn prefetch(&self, board: &T) {
+        self.inner.prefetch(&board.canonical_form());
+    }
+
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        self.inner.replacement_policy()
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.inner.has(&board.canonical_form())
+    }
+
+    /// Iterates over the entries of the wrapped table, which are all in canonical form.
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        self.inner.iter()
+    }
+}
+
+/// Transposition table wrapper that only replaces scores according to a [`ReplacementPolicy`],
+/// e.g. so that exact scores found earlier in a search aren't overwritten by bounds.
+#[derive(Debug, Clone)]
+pub struct ReplacementPolicyTable<T: Eq + Hash + Game, Inner: TranspositionTable<T>> {
+    inner: Inner,
+    policy: ReplacementPolicy,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> ReplacementPolicyTable<T, Inner> {
+    pub fn new(inner: Inner, policy: ReplacementPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped transposition table.
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> TranspositionTable<T>
+    for ReplacementPolicyTable<T, Inner>
+{
+    fn get(&self, board: &T) -> Option<Score> {
+        self.inner.get(board)
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        if self.policy.should_replace(self.inner.get(&board), score) {
+            self.inner.insert(board, score);
+        }
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        if self.policy.should_replace(self.inner.get(&board), score) {
+            self.inner.insert_with_depth(board, score, depth);
+        }
+    }
+
+    fn prefetch(&self, board: &T) {
+        self.inner.prefetch(board);
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.inner.has(board)
+    }
+
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        self.policy
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        self.inner.iter()
+    }
+}
+
+/// Exact scores of positions, e.g. from [`crate::solve_all_positions`], for looking them up
+/// after solving.
+///
+/// Unlike a transposition table, this never stores bounds, and it isn't used
+/// during search (see [`GameDB`] for solved positions the solver can use).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct ScoreTable<T: Hash + Eq>(HashMap<T, isize>);
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq + Game> ScoreTable<T> {
+    /// Collects the exact scores of a transposition table, skipping its bounds.
+    ///
+    /// # Panics
+    ///
+    /// If the table doesn't support [`TranspositionTable::iter`].
+    pub fn from_exact_scores(transposition_table: &dyn TranspositionTable<T>) -> Self {
+        Self(
+            transposition_table
+                .iter()
+                .filter_map(|(board, score)| match score {
+                    Score::Exact(score) => Some((board, score)),
+                    Score::LowerBound(_) | Score::UpperBound(_) => None,
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq> ScoreTable<T> {
+    /// Returns the score of `game`, if it's in the table.
+    pub fn lookup(&self, game: &T) -> Option<isize> {
+        self.0.get(game).copied()
+    }
+
+    /// The amount of positions in the table.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the table has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Every position in the table, with its score.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, isize)> {
+        self.0.iter().map(|(game, score)| (game, *score))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq, S: BuildHasher> From<HashMap<T, isize, S>> for ScoreTable<T> {
+    fn from(scores: HashMap<T, isize, S>) -> Self {
+        Self(scores.into_iter().collect())
+    }
+}
+
+/// Transposition table wrapper that remembers how deep each board was searched,
+/// and only replaces an entry with one from a search at least as deep
+/// (depth-preferred replacement).
+///
+/// A score from a deeper search is more reliable, so it shouldn't be overwritten
+/// by a shallower one. Boards inserted without a depth (with [`TranspositionTable::insert`])
+/// are assumed to have been searched completely, so they always replace their entry,
+/// and are never replaced by depth-limited results.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct DepthAwareTranspositionTable<T: Eq + Hash + Game, Inner: TranspositionTable<T>> {
+    inner: Inner,
+    depths: HashMap<T, u8>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> DepthAwareTranspositionTable<T, Inner> {
+    /// Wraps a transposition table, which should start out empty.
+    pub fn new(inner: Inner) -> Self {
+        Self {
+            inner,
+            depths: HashMap::new(),
+        }
+    }
+
+    /// The depth `board` was searched to, if it's in the table.
+    pub fn depth(&self, board: &T) -> Option<u8> {
+        self.depths.get(board).copied()
+    }
+
+    /// Returns the wrapped transposition table.
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T> + Default> Default
+    for DepthAwareTranspositionTable<T, Inner>
+{
+    fn default() -> Self {
+        Self::new(Inner::default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Game, Inner: TranspositionTable<T>> TranspositionTable<T>
+    for DepthAwareTranspositionTable<T, Inner>
+{
+    fn get(&self, board: &T) -> Option<Score> {
+        self.inner.get(board)
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        self.insert_with_depth(board, score, u8::MAX);
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        if self.depth(&board).is_some_and(|existing| depth < existing) {
+            return;
+        }
+
+        self.depths.insert(board.clone(), depth);
+        self.inner.insert_with_depth(board, score, depth);
+    }
+
+    fn prefetch(&self, board: &T) {
+        self.inner.prefetch(board);
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.inner.has(board)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        self.inner.iter()
+    }
+}
+
+/// A board stored in a [`TwoLevelTranspositionTable`], alongside how deep it was searched.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+struct StoredEntry<T> {
+    board: T,
+    score: Score,
+    depth: u8,
+}
+
+/// Fixed-size transposition table that keeps two boards per bucket, as many chess engines do:
+/// one slot is depth-preferred, and the other always takes the newest board.
+///
+/// Boards searched at least as deep as the depth-preferred one take its slot,
+/// demoting it to the always-replace slot; any other board goes in the always-replace slot
+/// (unless a deeper score of it is already kept).
+/// This keeps the most expensive scores around, while still remembering recent ones,
+/// which usually gives more hits than either policy alone once the table fills up.
+///
+/// Boards inserted without a depth (with [`TranspositionTable::insert`]) were searched
+/// until the end of the game, so their depth is their amount of remaining moves
+/// (see [`Game::max_moves`]).
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct TwoLevelTranspositionTable<T: Hash + Eq> {
+    buckets: Vec<Bucket<T>>,
+}
+
+/// The `(depth-preferred, always-replace)` slots of a [`TwoLevelTranspositionTable`] bucket.
+#[cfg(feature = "std")]
+type Bucket<T> = (Option<StoredEntry<T>>, Option<StoredEntry<T>>);
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq> TwoLevelTranspositionTable<T> {
+    /// Creates a table with `buckets` buckets (at least one), which can hold twice as many boards.
+    pub fn with_capacity(buckets: usize) -> Self {
+        Self {
+            buckets: core::iter::repeat_with(|| (None, None))
+                .take(buckets.max(1))
+                .collect(),
+        }
+    }
+
+    fn bucket(&self, board: &T) -> usize {
+        let mut hasher = DefaultHasher::new();
+        board.hash(&mut hasher);
+        (hasher.finish() % self.buckets.len() as u64) as usize
+    }
+
+    /// The depth `board` was searched to, if it's in the table.
+    pub fn depth(&self, board: &T) -> Option<u8> {
+        self.entry(board).map(|entry| entry.depth)
+    }
+
+    fn entry(&self, board: &T) -> Option<&StoredEntry<T>> {
+        let (deep, recent) = &self.buckets[self.bucket(board)];
+        [deep, recent]
+            .into_iter()
+            .flatten()
+            .find(|entry| entry.board == *board)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Game> TranspositionTable<T> for TwoLevelTranspositionTable<T> {
+    fn get(&self, board: &T) -> Option<Score> {
+        self.entry(board).map(|entry| entry.score)
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        let depth = board.max_moves().map_or(u8::MAX, |max_moves| {
+            max_moves
+                .saturating_sub(board.move_count())
+                .min(u8::MAX as usize) as u8
+        });
+        self.insert_with_depth(board, score, depth);
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        let index = self.bucket(&board);
+        let (deep, recent) = &mut self.buckets[index];
+
+        if let Some(existing) = deep.as_ref().filter(|existing| depth < existing.depth) {
+            // the deeper score of this board is already kept.
+            if existing.board != board {
+                *recent = Some(StoredEntry {
+                    board,
+                    score,
+                    depth,
+                });
+            }
+            return;
+        }
+
+        // an older score of the same board would shadow (or duplicate) this one.
+        if recent
+            .as_ref()
+            .is_some_and(|existing| existing.board == board)
+        {
+            *recent = None;
+        }
+
+        if let Some(demoted) = deep.replace(StoredEntry {
+            board,
+            score,
+            depth,
+        }) {
+            if demoted.board != deep.as_ref().unwrap().board {
+                *recent = Some(demoted);
+            }
+        }
+    }
+
+    fn prefetch(&self, board: &T) {
+        prefetch_read(&self.buckets[self.bucket(board)]);
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.entry(board).is_some()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        Box::new(
+            self.buckets
+                .iter()
+                .flat_map(|(deep, recent)| [deep, recent])
+                .flatten()
+                .map(|entry| (entry.board.clone(), entry.score)),
+        )
+    }
+}
+
+/// A read-only database of solved positions, e.g. an endgame table.
+///
+/// As a transposition table, this is an oracle: every position in it has an exact score,
+/// and nothing new is ever stored, so it's usually paired with a regular table
+/// in a [`CompositeTable`].
+///
+/// Scores must be the ones [`crate::solve`] gives (with the default configuration),
+/// from the perspective of the player to move.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct GameDB<T: Game + Hash + Eq>(HashMap<T, isize>);
+
+#[cfg(feature = "std")]
+impl<T: Game + Hash + Eq> GameDB<T> {
+    /// Creates a database out of positions and their scores.
+    pub fn from_solved(positions: Vec<(T, isize)>) -> Self {
+        Self(positions.into_iter().collect())
+    }
+
+    /// Returns the score of `board`, if it's in the database.
+    pub fn score(&self, board: &T) -> Option<isize> {
+        self.0.get(board).copied()
+    }
+
+    /// The amount of positions in the database.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the database has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Game + Hash + Eq> TranspositionTable<T> for GameDB<T> {
+    fn get(&self, board: &T) -> Option<Score> {
+        self.score(board).map(Score::Exact)
+    }
+
+    /// Does nothing, as the database is read-only.
+    fn insert(&mut self, _board: T, _score: Score) {}
+
+    fn has(&self, board: &T) -> bool {
+        self.0.contains_key(board)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        Box::new(
+            self.0
+                .iter()
+                .map(|(board, score)| (board.clone(), Score::Exact(*score))),
+        )
+    }
+}
+
+/// Transposition table that looks boards up in `primary` first, then in `fallback`.
+///
+/// New scores are only stored in `fallback`, which makes this a good fit
+/// for pairing a read-only table (such as a [`GameDB`]) with a regular one.
+#[derive(Debug, Clone)]
+pub struct CompositeTable<T: Eq + Hash + Game, Primary, Fallback> {
+    primary: Primary,
+    fallback: Fallback,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Eq + Hash + Game, Primary: TranspositionTable<T>, Fallback: TranspositionTable<T>>
+    CompositeTable<T, Primary, Fallback>
+{
+    pub fn new(primary: Primary, fallback: Fallback) -> Self {
+        Self {
+            primary,
+            fallback,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn primary(&self) -> &Primary {
+        &self.primary
+    }
+
+    pub fn fallback(&self) -> &Fallback {
+        &self.fallback
+    }
+
+    /// Returns the wrapped tables.
+    pub fn into_inner(self) -> (Primary, Fallback) {
+        (self.primary, self.fallback)
+    }
+}
+
+impl<T: Eq + Hash + Game, Primary: TranspositionTable<T>, Fallback: TranspositionTable<T>>
+    TranspositionTable<T> for CompositeTable<T, Primary, Fallback>
+{
+    fn get(&self, board: &T) -> Option<Score> {
+        self.primary.get(board).or_else(|| self.fallback.get(board))
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        self.fallback.insert(board, score);
+    }
+
+    fn insert_with_depth(&mut self, board: T, score: Score, depth: u8) {
+        self.fallback.insert_with_depth(board, score, depth);
+    }
+
+    fn prefetch(&self, board: &T) {
+        self.primary.prefetch(board);
+        self.fallback.prefetch(board);
+    }
+
+    fn replacement_policy(&self) -> ReplacementPolicy {
+        self.fallback.replacement_policy()
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.primary.has(board) || self.fallback.has(board)
+    }
+
+    /// Iterates over the entries of both tables, `primary` first.
+    fn iter(&self) -> Box<dyn Iterator<Item = (T, Score)> + '_> {
+        Box::new(self.primary.iter().chain(self.fallback.iter()))
+    }
 }
 
 /// Powerful transposition table that uses an underlying concurrent
@@ -66,10 +846,16 @@ impl<
 {
     /// Create a new transposition cache with the given capacity and hasher.
     pub fn with_capacity(capacity: u64) -> Self {
+        Self::with_capacity_and_hasher(capacity, S::default())
+    }
+
+    /// Create a new transposition cache with the given capacity, hashing with `hasher`
+    /// (e.g. one with a specific seed).
+    pub fn with_capacity_and_hasher(capacity: u64, hasher: S) -> Self {
         Self(
             Cache::builder()
                 .max_capacity(capacity)
-                .build_with_hasher(S::default()),
+                .build_with_hasher(hasher),
         )
     }
 
@@ -77,16 +863,39 @@ impl<
     /// an estimated three fourths of the remaining memory.
     #[must_use]
     pub fn new() -> Self {
-        let score_size = std::mem::size_of::<Score>() as u64;
+        Self::with_hasher(S::default())
+    }
+
+    /// Like [`TranspositionCache::new`], hashing with `hasher`.
+    #[must_use]
+    pub fn with_hasher(hasher: S) -> Self {
+        let score_size = core::mem::size_of::<Score>() as u64;
 
-        Self::with_capacity(
+        Self::with_capacity_and_hasher(
             // get three fourths of the memory, and divide that by the size of a score
             // to get the number of scores that can fit in the cache
             (sysinfo::System::new_all().total_memory() * 3 / 4) / score_size,
+            hasher,
         )
     }
 }
 
+/// Builds [`XxHash64`](twox_hash::XxHash64) hashers with a fixed seed.
+/// The default seed is 0.
+/// This requires the `xxhash` feature to be enabled.
+#[cfg(feature = "xxhash")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeededXxHashBuilder64(pub u64);
+
+#[cfg(feature = "xxhash")]
+impl BuildHasher for SeededXxHashBuilder64 {
+    type Hasher = twox_hash::XxHash64;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        twox_hash::XxHash64::with_seed(self.0)
+    }
+}
+
 #[cfg(feature = "rayon")]
 impl<
         K: Eq + Hash + Game + Send + Sync,
@@ -115,4 +924,358 @@ impl<
     fn has(&self, board: &K) -> bool {
         self.0.contains_key(board)
     }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, Score)> + '_> {
+        Box::new(
+            self.0
+                .iter()
+                .map(|(board, score)| ((*board).clone(), score)),
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<
+        K: Eq + Hash + Game + Send + Sync + 'static,
+        S: BuildHasher + Default + Send + Sync + Clone + 'static,
+    > IntoIterator for TranspositionCache<K, S>
+{
+    type Item = (K, Score);
+    type IntoIter = alloc::vec::IntoIter<(K, Score)>;
+
+    /// Drains the cache into its entries.
+    /// Since the cache is concurrent, this has to collect every entry beforehand.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+            .iter()
+            .map(|(board, score)| (Arc::unwrap_or_clone(board), score))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_merge_to_the_most_constraining() {
+        assert_eq!(
+            Score::LowerBound(1).merge(Score::LowerBound(3)),
+            Score::LowerBound(3)
+        );
+        assert_eq!(
+            Score::UpperBound(1).merge(Score::UpperBound(3)),
+            Score::UpperBound(1)
+        );
+        assert_eq!(Score::LowerBound(1).merge(Score::Exact(2)), Score::Exact(2));
+    }
+
+    #[test]
+    fn merging_mixed_bounds() {
+        // only one bound can be kept.
+        assert_eq!(
+            Score::LowerBound(2).merge(Score::UpperBound(5)),
+            Score::LowerBound(2)
+        );
+        assert_eq!(
+            Score::UpperBound(5).merge(Score::LowerBound(2)),
+            Score::LowerBound(2)
+        );
+
+        // bounds that meet pin the score down.
+        assert_eq!(
+            Score::LowerBound(3).merge(Score::UpperBound(3)),
+            Score::Exact(3)
+        );
+        assert_eq!(
+            Score::UpperBound(3).merge(Score::LowerBound(3)),
+            Score::Exact(3)
+        );
+    }
+
+    // (these solve the Nim test game, which needs `std`)
+    #[cfg(feature = "std")]
+    mod nim {
+        use std::collections::HashMap;
+
+        use super::*;
+        use crate::{
+            game::max_score, negamax_raw, solve, solve_all_positions, stats::Stats, tests::Nim,
+            NegamaxOptions,
+        };
+
+        #[test]
+        fn transposition_tables_load_from_entries() {
+            let nim = Nim::new(vec![2, 3]);
+            let mut solved = HashMap::new();
+            solve(&nim, &mut solved, None).unwrap();
+
+            let mut entries = TranspositionTable::iter(&solved).collect::<Vec<_>>();
+            let rest = entries.split_off(entries.len() / 2);
+
+            let mut table = transposition_table_from_iter(entries);
+            table.extend(rest);
+            assert_eq!(table, solved);
+        }
+
+        #[test]
+        fn merged_tables_search_less() {
+            let nim = Nim::new(vec![2, 3, 4]);
+            let after = |m| {
+                let mut board = nim.clone();
+                board.make_move(&m).unwrap();
+                board
+            };
+
+            // two workers, each solving the position after a different move.
+            let boards = [after((0, 1)), after((2, 3))];
+            let [first, second] = boards.clone().map(|board| {
+                let mut table = HashMap::new();
+                solve(&board, &mut table, None).unwrap();
+                table
+            });
+
+            let mut merged = first.clone();
+            merged.merge(TranspositionTable::iter(&second));
+            assert!(merged.len() > first.len());
+
+            let explored = |game: &Nim, mut table: HashMap<Nim, Score>| {
+                let stats = Stats::new(game.player(), game.move_count());
+                let score = solve(game, &mut table, Some(&stats)).unwrap();
+                (score, stats.states_explored.into_inner())
+            };
+
+            // the merged table knows about the second worker's positions too,
+            // so both the root and the second worker's position need less searching.
+            for game in [&nim, &boards[1]] {
+                let (score, first_explored) = explored(game, first.clone());
+                let (merged_score, merged_explored) = explored(game, merged.clone());
+                assert_eq!(score, merged_score);
+                assert!(
+                    merged_explored < first_explored,
+                    "{merged_explored} states explored, over {first_explored}"
+                );
+            }
+        }
+
+        #[test]
+        fn game_db_answers_solved_positions() {
+            let nim = Nim::new(vec![2, 3, 4]);
+
+            // solve every position after the first move ahead of time
+            let solved = nim
+                .children()
+                .map(|(_, board)| {
+                    let score = solve(&board, &mut HashMap::new(), None).unwrap();
+                    (board, score)
+                })
+                .collect::<Vec<_>>();
+            let mut table = CompositeTable::new(GameDB::from_solved(solved), HashMap::new());
+
+            let stats = || Stats::new(nim.player(), nim.move_count());
+            let (with_db, without_db) = (stats(), stats());
+            assert_eq!(
+                solve(&nim, &mut table, Some(&with_db)).unwrap(),
+                solve(&nim, &mut HashMap::new(), Some(&without_db)).unwrap()
+            );
+
+            // positions past the first move are never searched
+            assert!(with_db.states_explored.into_inner() < without_db.states_explored.into_inner());
+            assert_eq!(table.primary().len(), nim.possible_moves().count());
+        }
+
+        #[test]
+        fn replacement_policies() {
+            let nim = Nim::new(vec![2, 3]);
+            let insert_twice = |policy, first, second| {
+                let mut table = ReplacementPolicyTable::new(HashMap::new(), policy);
+                table.insert(nim.clone(), first);
+                table.insert(nim.clone(), second);
+                table.get(&nim).unwrap()
+            };
+
+            use ReplacementPolicy::*;
+            use Score::*;
+            assert_eq!(
+                insert_twice(AlwaysReplace, Exact(1), UpperBound(2)),
+                UpperBound(2)
+            );
+            assert_eq!(
+                insert_twice(NeverReplace, UpperBound(2), Exact(1)),
+                UpperBound(2)
+            );
+            assert_eq!(insert_twice(PreferExact, Exact(1), UpperBound(2)), Exact(1));
+            assert_eq!(
+                insert_twice(PreferExact, LowerBound(1), UpperBound(2)),
+                UpperBound(2)
+            );
+            assert_eq!(
+                insert_twice(PreferLowerBound, LowerBound(1), UpperBound(2)),
+                LowerBound(1)
+            );
+            assert_eq!(
+                insert_twice(PreferLowerBound, UpperBound(2), LowerBound(1)),
+                LowerBound(1)
+            );
+            assert_eq!(
+                insert_twice(PreferLowerBound, Exact(1), LowerBound(1)),
+                Exact(1)
+            );
+
+            let nim = Nim::new(vec![2, 3, 4]);
+            for policy in [AlwaysReplace, NeverReplace, PreferLowerBound, PreferExact] {
+                let mut table = ReplacementPolicyTable::new(HashMap::new(), policy);
+                assert_eq!(table.replacement_policy(), policy);
+                assert_eq!(
+                    solve(&nim, &mut table, None).unwrap(),
+                    solve(&nim, &mut HashMap::new(), None).unwrap(),
+                    "{policy:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn depth_preferred_replacement() {
+            let nim = Nim::new(vec![1, 1]);
+            let mut table = DepthAwareTranspositionTable::<Nim, HashMap<_, _>>::default();
+
+            table.insert_with_depth(nim.clone(), Score::UpperBound(0), 4);
+            table.insert_with_depth(nim.clone(), Score::Exact(1), 2);
+            assert_eq!(table.get(&nim), Some(Score::UpperBound(0)));
+            assert_eq!(table.depth(&nim), Some(4));
+
+            table.insert_with_depth(nim.clone(), Score::LowerBound(1), 4);
+            assert_eq!(table.get(&nim), Some(Score::LowerBound(1)));
+
+            // results without a depth are exhaustive, and always win.
+            table.insert(nim.clone(), Score::Exact(-1));
+            table.insert_with_depth(nim.clone(), Score::Exact(1), 200);
+            assert_eq!(table.get(&nim), Some(Score::Exact(-1)));
+
+            // depth-limited searches record how deep they searched.
+            let max_score = max_score(&nim).unwrap();
+            let mut table = DepthAwareTranspositionTable::<Nim, HashMap<_, _>>::default();
+            let options = NegamaxOptions::new(-max_score, max_score).with_depth(2);
+            assert_eq!(
+                negamax_raw(&nim, &mut table, options).unwrap(),
+                solve(&nim, &mut HashMap::new(), None).unwrap()
+            );
+            assert_eq!(table.depth(&nim), Some(2));
+        }
+
+        #[test]
+        fn two_level_replacement() {
+            // a single bucket, so that every board competes for the same slots.
+            let mut table = TwoLevelTranspositionTable::with_capacity(1);
+            let [deep, shallow, newest] = [vec![1], vec![2], vec![3]].map(Nim::new);
+
+            table.insert_with_depth(deep.clone(), Score::Exact(1), 8);
+            table.insert_with_depth(shallow.clone(), Score::Exact(-1), 2);
+            assert_eq!(table.get(&deep), Some(Score::Exact(1)));
+            assert_eq!(table.get(&shallow), Some(Score::Exact(-1)));
+
+            // shallow boards only ever evict the always-replace slot.
+            table.insert_with_depth(newest.clone(), Score::LowerBound(1), 3);
+            assert_eq!(table.get(&deep), Some(Score::Exact(1)));
+            assert!(!table.has(&shallow));
+
+            // deeper boards demote the depth-preferred one.
+            table.insert_with_depth(shallow.clone(), Score::UpperBound(0), 9);
+            assert_eq!(table.depth(&shallow), Some(9));
+            assert_eq!(table.depth(&deep), Some(8));
+            assert!(!table.has(&newest));
+
+            // a shallower score doesn't replace a deeper one of the same board.
+            table.insert_with_depth(shallow.clone(), Score::Exact(0), 1);
+            assert_eq!(table.get(&shallow), Some(Score::UpperBound(0)));
+            assert!(table.has(&deep));
+            assert_eq!(table.iter().count(), 2);
+
+            // even a tiny table solves correctly.
+            for heaps in [vec![1, 2, 3], vec![3, 4, 5]] {
+                let nim = Nim::new(heaps);
+                assert_eq!(
+                    solve(
+                        &nim,
+                        &mut TwoLevelTranspositionTable::with_capacity(4),
+                        None
+                    )
+                    .unwrap(),
+                    solve(&nim, &mut HashMap::new(), None).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn score_tables() {
+            let nim = Nim::new(vec![2, 3]);
+            let scores = solve_all_positions(&nim, &mut HashMap::new(), None).unwrap();
+
+            let table = ScoreTable::from(scores.clone());
+            assert_eq!(table.len(), scores.len());
+            assert_eq!(table.lookup(&nim), Some(scores[&nim]));
+            assert_eq!(table.lookup(&Nim::new(vec![9])), None);
+
+            // only exact scores are kept from a transposition table.
+            let mut transposition_table = HashMap::new();
+            transposition_table.insert(nim.clone(), Score::Exact(3));
+            transposition_table.insert(Nim::new(vec![1]), Score::LowerBound(1));
+            let table = ScoreTable::from_exact_scores(&transposition_table);
+            assert_eq!(table.iter().collect::<Vec<_>>(), [(&nim, 3)]);
+        }
+
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn transposition_table_iteration() {
+            use std::collections::hash_map::RandomState;
+
+            let nim = Nim::new(vec![2, 3, 4]);
+
+            let mut map = HashMap::new();
+            solve(&nim, &mut map, None).unwrap();
+
+            let entries: HashMap<_, _> = TranspositionTable::iter(&map).collect();
+            assert!(!entries.is_empty());
+            assert_eq!(entries, map);
+
+            let mut cache = Arc::new(TranspositionCache::<Nim, RandomState>::with_capacity(
+                10_000,
+            ));
+            solve(&nim, &mut cache, None).unwrap();
+
+            let cached: HashMap<_, _> = cache.iter().collect();
+            assert_eq!(cached, map);
+
+            let cache = Arc::into_inner(cache).unwrap();
+            assert_eq!(cache.into_iter().collect::<HashMap<_, _>>(), map);
+        }
+
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn concurrent_tables_are_sync() {
+            use std::collections::hash_map::RandomState;
+
+            fn solve_on_another_thread(
+                nim: Nim,
+                mut table: impl SyncTranspositionTable<Nim> + 'static,
+            ) -> isize {
+                std::thread::spawn(move || solve(&nim, &mut table, None).unwrap())
+                    .join()
+                    .unwrap()
+            }
+
+            let nim = Nim::new(vec![1, 2, 3]);
+            let score = solve(&nim, &mut HashMap::new(), None).unwrap();
+
+            let shared_tt = Arc::new(TranspositionCache::<Nim, RandomState>::with_capacity(
+                10_000,
+            ));
+            assert_eq!(solve_on_another_thread(nim.clone(), shared_tt), score);
+            assert_eq!(
+                solve_on_another_thread(nim, HashMap::<Nim, Score>::new()),
+                score
+            );
+        }
+    }
 }
@@ -0,0 +1,265 @@
+//! An explicit graph of a game's positions.
+//!
+//! The search in [`crate::negamax_soft_fail`] treats the game as a tree,
+//! and only relies on its transposition table to notice positions reached more than once.
+//! For games whose positions form a DAG, [`GameGraph`] stores every position once,
+//! along with the moves between them, so all of them can be analyzed in one pass.
+
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    game::{max_score, min_score, Game, GameState},
+    player::TwoPlayer,
+    GameSolveError,
+};
+
+/// Every position reachable from some initial position (up to a depth),
+/// with the moves leading out of each one.
+pub struct GameGraph<T: Game + Hash + Eq> {
+    edges: HashMap<T, Vec<(T::Move, T)>>,
+}
+
+impl<T: Game + Hash + Eq> GameGraph<T> {
+    /// Builds the graph of every position reachable from `initial` in at most `depth` moves,
+    /// via breadth-first search.
+    ///
+    /// Positions `depth` moves away are part of the graph, but aren't expanded,
+    /// so they have no edges. Neither do positions where the game is over.
+    pub fn build_graph(initial: &T, depth: usize) -> Result<Self, GameSolveError<T>> {
+        let mut edges = HashMap::new();
+        let mut queue = VecDeque::from([(initial.clone(), 0)]);
+
+        while let Some((game, distance)) = queue.pop_front() {
+            if edges.contains_key(&game) {
+                continue;
+            }
+
+            let mut children = Vec::new();
+            if distance < depth && game.state() == GameState::Playable {
+                for m in game.possible_moves() {
+                    let mut board = game.clone();
+                    board
+                        .make_move(&m)
+                        .map_err(|err| GameSolveError::MoveError(err))?;
+
+                    if !edges.contains_key(&board) {
+                        queue.push_back((board.clone(), distance + 1));
+                    }
+                    children.push((m, board));
+                }
+            }
+
+            edges.insert(game, children);
+        }
+
+        Ok(Self { edges })
+    }
+
+    /// Returns the moves out of `position`, and the positions they lead to,
+    /// or `None` if `position` isn't in the graph.
+    pub fn edges(&self, position: &T) -> Option<&[(T::Move, T)]> {
+        self.edges.get(position).map(Vec::as_slice)
+    }
+
+    /// Returns true if `position` is in the graph.
+    pub fn contains(&self, position: &T) -> bool {
+        self.edges.contains_key(position)
+    }
+
+    /// Every position in the graph, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = &T> {
+        self.edges.keys()
+    }
+
+    /// The amount of positions in the graph.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns true if the graph has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+/// Solves `start` over the positions of `graph`, scoring every position only once.
+///
+/// Scores are the same as [`crate::solve`]'s, provided the graph was built deep enough
+/// to reach the end of the game. Positions at the edge of the graph that aren't over yet
+/// score 0, like positions past the search horizon of a depth-limited search,
+/// and so do positions repeated along a line of play.
+///
+/// Returns `None` if `start` isn't in the graph, or if the game has no [`Game::max_moves`].
+pub fn solve_on_graph<T: Game<Player = impl TwoPlayer> + Hash + Eq>(
+    graph: &GameGraph<T>,
+    start: &T,
+) -> Option<isize> {
+    let start = graph.edges.get_key_value(start)?.0;
+
+    graph_score(
+        graph,
+        start,
+        &mut HashMap::new(),
+        &mut HashSet::new(),
+        &mut false,
+    )
+}
+
+/// The score of `game` for the player to move, memoized in `scores`.
+///
+/// Repeated positions score 0 wherever they're reached, so scores that depend on one
+/// also depend on the line that led to them: `cut` is set if any did, and those aren't memoized.
+fn graph_score<'a, T: Game<Player = impl TwoPlayer> + Hash + Eq>(
+    graph: &'a GameGraph<T>,
+    game: &'a T,
+    scores: &mut HashMap<&'a T, isize>,
+    line: &mut HashSet<&'a T>,
+    cut: &mut bool,
+) -> Option<isize> {
+    // this position loops back into the line (or the game's own history), which is a draw,
+    // like in [`crate::solve`].
+    if line.contains(game) || game.is_repeated_position() {
+        *cut = true;
+        return Some(0);
+    }
+
+    if let Some(&score) = scores.get(game) {
+        return Some(score);
+    }
+
+    let mut cut_below = false;
+
+    let score = if game.is_draw() || game.is_repetition_draw() {
+        0
+    } else {
+        match game.state() {
+            GameState::Tie => 0,
+            GameState::Win(winning_player) => {
+                if game.player() == winning_player {
+                    max_score(game)?
+                } else {
+                    min_score(game)?
+                }
+            }
+            GameState::Playable => {
+                let children = graph.edges(game).unwrap_or_default();
+
+                if children.is_empty() {
+                    // a playable game without any moves is lost for the player to move
+                    // (like in [`crate::solve`]), and any other is at the edge of the graph.
+                    if game.possible_moves().next().is_none() {
+                        min_score(game)?
+                    } else {
+                        0
+                    }
+                } else {
+                    line.insert(game);
+                    let best = children
+                        .iter()
+                        .map(|(_, child)| {
+                            graph_score(graph, child, scores, line, &mut cut_below).map(|s| -s)
+                        })
+                        .collect::<Option<Vec<_>>>()?
+                        .into_iter()
+                        .max()
+                        .unwrap();
+                    line.remove(game);
+
+                    best
+                }
+            }
+        }
+    };
+
+    if cut_below {
+        *cut = true;
+    } else {
+        scores.insert(game, score);
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+    use crate::{player::PartizanPlayer, solve, solve_all_positions, tests::Nim};
+
+    #[test]
+    fn graph_scores_match_solve() {
+        let nim = Nim::new(vec![2, 3]);
+        let graph = GameGraph::build_graph(&nim, usize::MAX).unwrap();
+
+        assert_eq!(
+            graph.len(),
+            solve_all_positions(&nim, &mut HashMap::new(), None)
+                .unwrap()
+                .len()
+        );
+        for position in graph.positions() {
+            assert_eq!(
+                solve_on_graph(&graph, position),
+                Some(solve(position, &mut HashMap::new(), None).unwrap())
+            );
+        }
+
+        let shallow = GameGraph::build_graph(&nim, 1).unwrap();
+        assert_eq!(shallow.len(), 1 + nim.possible_moves().count());
+        assert!(shallow.edges(&nim).is_some_and(|edges| !edges.is_empty()));
+        assert_eq!(solve_on_graph(&shallow, &Nim::new(vec![1, 1])), None);
+    }
+
+    /// The moves out of each position of [`Maze`]. Position 1 has none, so it's lost.
+    const MAZE: [&[u8]; 4] = [&[2, 3], &[], &[1, 3], &[2]];
+
+    /// A walk through [`MAZE`], which only remembers where it is,
+    /// so positions 2 and 3 form a cycle in its graph.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Maze(u8);
+
+    impl Game for Maze {
+        type Move = u8;
+        type Iter<'a> = core::iter::Copied<core::slice::Iter<'static, u8>>;
+        type MoveError = Infallible;
+        type Player = PartizanPlayer;
+
+        fn move_count(&self) -> usize {
+            0
+        }
+
+        fn max_moves(&self) -> Option<usize> {
+            Some(MAZE.len())
+        }
+
+        fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+            self.0 = *m;
+            Ok(())
+        }
+
+        fn possible_moves(&self) -> Self::Iter<'_> {
+            MAZE[self.0 as usize].iter().copied()
+        }
+
+        fn state(&self) -> GameState<Self::Player> {
+            GameState::Playable
+        }
+
+        fn player(&self) -> Self::Player {
+            PartizanPlayer::Left
+        }
+    }
+
+    #[test]
+    fn cycles_dont_leak_into_other_lines() {
+        let graph = GameGraph::build_graph(&Maze(0), usize::MAX).unwrap();
+        let max_score = max_score(&Maze(0)).unwrap();
+
+        // searching 0 -> 2 -> 3 cuts 3's only move, back to 2. reached from 0 directly,
+        // 3 has to move to 2 instead, which then wins by moving to 1.
+        assert_eq!(solve_on_graph(&graph, &Maze(3)), Some(-max_score));
+        assert_eq!(solve_on_graph(&graph, &Maze(0)), Some(max_score));
+    }
+}
@@ -0,0 +1,241 @@
+//! Utilities for testing and debugging game implementations.
+
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    game::Game,
+    player::TwoPlayer,
+    solve,
+    transposition::{Score, TranspositionTable},
+    GameSolveError,
+};
+
+/// The amount of lines of play `depth` moves long from `game`,
+/// counting lines that end the game early once.
+///
+/// Comparing this against known counts is a quick way to check move generation.
+pub fn perft<T: Game>(game: &T, depth: usize) -> u64 {
+    if depth == 0 || game.state().is_terminal() {
+        return 1;
+    }
+
+    // illegal moves don't count as lines of play.
+    game.children()
+        .map(|(_, board)| perft(&board, depth - 1))
+        .sum()
+}
+
+/// Like [`perft`], but fingerprints the positions at the end of every line
+/// by adding up their hashes, instead of only counting them.
+///
+/// Two move generators with the same [`perft`] can still differ in which positions they reach,
+/// so this pins down the positions themselves: adding, removing, or changing any move
+/// changes the fingerprint. (Hashes are added rather than XOR-ed, since transpositions
+/// reach the same position through an even amount of lines, which XOR-ing would cancel out.) Hashes are computed with (a mixed version of)
+/// [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function),
+/// so fingerprints are the same on every platform and Rust version,
+/// as long as the game's [`Hash`] implementation doesn't change.
+pub fn perft_hash<T: Game + Hash>(game: &T, depth: usize) -> u64 {
+    if depth == 0 || game.state().is_terminal() {
+        let mut hasher = FnvHasher::default();
+        game.hash(&mut hasher);
+        return hasher.finish();
+    }
+
+    game.children()
+        .map(|(_, board)| perft_hash(&board, depth - 1))
+        .fold(0, u64::wrapping_add)
+}
+
+/// The move that turns `from` into `to`, if `to` is reachable from `from` in one move.
+///
+/// This tries every move, so it's meant for replay tools and debugging, not for searching.
+pub fn find_move_between<T: Game + Eq>(from: &T, to: &T) -> Option<T::Move> {
+    from.possible_moves().find(|m| {
+        let mut board = from.clone();
+        board.make_move(m).is_ok() && board == *to
+    })
+}
+
+/// The shortest sequence of at most `max_depth` moves that turns `from` into `to`,
+/// found with a breadth-first search. If `from` is `to`, this is empty.
+/// This requires the `std` feature to be enabled.
+#[cfg(feature = "std")]
+pub fn find_move_sequence_between<T: Game + Eq + Hash>(
+    from: &T,
+    to: &T,
+    max_depth: usize,
+) -> Option<Vec<T::Move>> {
+    let mut seen = HashSet::from([from.clone()]);
+    let mut queue = VecDeque::from([(from.clone(), Vec::new())]);
+
+    while let Some((game, moves)) = queue.pop_front() {
+        if game == *to {
+            return Some(moves);
+        }
+
+        if moves.len() == max_depth {
+            continue;
+        }
+
+        for m in game.possible_moves() {
+            let mut board = game.clone();
+            if board.make_move(&m).is_err() || seen.contains(&board) {
+                continue;
+            }
+
+            seen.insert(board.clone());
+            let mut moves = moves.clone();
+            moves.push(m);
+            queue.push_back((board, moves));
+        }
+    }
+
+    None
+}
+
+/// A position where the solver disagrees with a known solution, found by [`cross_validate_solve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError<T> {
+    pub position: T,
+    /// What the solver found for the position, which may only be a bound.
+    pub solver_score: Score,
+    pub expected_score: isize,
+}
+
+/// Solves `game`, and checks the score of every position the solver visited
+/// (i.e. every entry of `transposition_table`, along with `game` itself) against `known_solution`,
+/// e.g. an analytical solution of the game, to catch bugs in a new game implementation.
+///
+/// Analytical solutions usually only tell who wins (like the nim-sum of Nim), so scores
+/// are only compared by their sign: a position is wrong if the solver has it won, lost, or tied
+/// (or bounds it away from that) when `known_solution` doesn't.
+///
+/// # Panics
+///
+/// If `transposition_table` doesn't support [`TranspositionTable::iter`].
+pub fn cross_validate_solve<T: Game<Player = impl TwoPlayer + 'static> + Eq + Hash>(
+    game: &T,
+    transposition_table: &mut dyn TranspositionTable<T>,
+    known_solution: impl Fn(&T) -> isize,
+) -> Result<Vec<ValidationError<T>>, GameSolveError<T>> {
+    let score = solve(game, transposition_table, None)?;
+
+    let entries = transposition_table
+        .iter()
+        .filter(|(board, _)| board != game);
+    let errors = core::iter::once((game.clone(), Score::Exact(score)))
+        .chain(entries)
+        .filter_map(|(position, solver_score)| {
+            let expected_score = known_solution(&position);
+            let expected = expected_score.signum();
+
+            let agrees = match solver_score {
+                Score::Exact(score) => expected == score.signum(),
+                Score::LowerBound(min) => expected >= min.signum(),
+                Score::UpperBound(max) => expected <= max.signum(),
+            };
+
+            (!agrees).then_some(ValidationError {
+                position,
+                solver_score,
+                expected_score,
+            })
+        })
+        .collect();
+
+    Ok(errors)
+}
+
+/// A deterministic (but not DoS-resistant) hasher.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        // FNV barely mixes its last bytes, which adding hashes together would mostly cancel out,
+        // so this finishes with the mixer of [MurmurHash3](https://en.wikipedia.org/wiki/MurmurHash).
+        let mut hash = self.0;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        hash ^ (hash >> 33)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    // integers are hashed as little-endian 64-bit integers, no matter the platform.
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_i64(i64::from(i));
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_i64(i64::from(i));
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+// (the tests use the Nim test game, which needs `std`)
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::tests::Nim;
+
+    #[test]
+    fn solve_cross_validates_with_nim_sum() {
+        // the player to move wins exactly when the heaps don't XOR to 0.
+        let nim_sum = |nim: &Nim| if nim.is_lost() { -1 } else { 1 };
+
+        for heaps in [vec![3], vec![2, 2], vec![1, 2, 3], vec![2, 3, 4]] {
+            let nim = Nim::new(heaps.clone());
+            let errors = cross_validate_solve(&nim, &mut HashMap::new(), nim_sum).unwrap();
+            assert_eq!(errors, [], "{heaps:?}");
+        }
+
+        // the first player can't win every position.
+        let nim = Nim::new(vec![1, 2, 3]);
+        let errors = cross_validate_solve(&nim, &mut HashMap::new(), |_| 1).unwrap();
+        assert!(errors.iter().any(|error| error.position == nim));
+        assert!(errors.iter().all(|error| nim_sum(&error.position) == -1));
+    }
+}
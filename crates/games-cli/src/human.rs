@@ -1,32 +1,20 @@
 use std::{
     fmt::Display,
-    future::IntoFuture,
-    sync::{
-        atomic::{AtomicU64, AtomicUsize, Ordering},
-        Arc,
-    },
+    sync::{atomic::Ordering, Arc},
     time::Duration,
 };
 
 use anyhow::Result;
 use core::hash::Hash;
-use game_solver::{
-    game::Game,
-    par_move_scores,
-    player::TwoPlayer,
-    stats::{Stats, TerminalEnds},
-};
+use game_solver::{game::Game, par_move_scores, player::TwoPlayer, stats::Stats};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    layout::{Alignment, Rect},
+    layout::Rect,
     style::Stylize,
     symbols::border,
     text::{Line, Text},
-    widgets::{
-        block::{Position, Title},
-        Block, Paragraph, Widget,
-    },
+    widgets::{Block, Paragraph, Widget},
     DefaultTerminal, Frame,
 };
 use std::fmt::Debug;
@@ -82,15 +70,11 @@ impl<G: Game> App<G> {
 
 impl<G: Game> Widget for &App<G> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let title = Title::from(" game-solver ".bold().green());
-        let instructions = Title::from(Line::from(vec![" Quit ".into(), "<Q> ".blue().bold()]));
+        let title = Line::from(" game-solver ".bold().green());
+        let instructions = Line::from(vec![" Quit ".into(), "<Q> ".blue().bold()]);
         let block = Block::bordered()
-            .title(title.alignment(Alignment::Center))
-            .title(
-                instructions
-                    .alignment(Alignment::Center)
-                    .position(Position::Bottom),
-            )
+            .title_top(title.centered())
+            .title_bottom(instructions.centered())
             .border_set(border::THICK);
 
         let cache_text = Text::from(vec![
@@ -118,6 +102,14 @@ impl<G: Game> Widget for &App<G> {
                     .to_string()
                     .yellow(),
             ]),
+            Line::from(vec![
+                "Re-searches: ".into(),
+                self.stats
+                    .researches
+                    .load(Ordering::Relaxed)
+                    .to_string()
+                    .yellow(),
+            ]),
             Line::from(vec![
                 "Terminal Nodes: (winning: ".into(),
                 self.stats
@@ -178,15 +170,7 @@ where
 {
     let mut terminal = ratatui::init();
 
-    let stats = Arc::new(Stats {
-        states_explored: AtomicU64::new(0),
-        max_depth: AtomicUsize::new(0),
-        cache_hits: AtomicU64::new(0),
-        pruning_cutoffs: AtomicU64::new(0),
-        terminal_ends: TerminalEnds::default(),
-        original_player: game.player(),
-        original_move_count: game.move_count(),
-    });
+    let stats = Arc::new(Stats::new(game.player(), game.move_count()));
 
     let exit = CancellationToken::new();
 
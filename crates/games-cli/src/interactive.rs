@@ -1,19 +1,21 @@
-use std::{fmt::{Debug, Display}, str::FromStr};
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
 
-use games::util::move_failable;
 use game_solver::{game::Game, player::TwoPlayer};
+use games::util::move_failable;
 
 use games_cli::robot::announce_player;
 use owo_colors::OwoColorize;
 
 use dialoguer::{theme::ColorfulTheme, Input};
 
-pub fn play_interactive<
-    T: Game<Player = impl TwoPlayer + Debug + 'static>
-        + Display
->(
-    mut game: T
-) where <T as Game>::Move: FromStr + Debug, <<T as Game>::Move as FromStr>::Err: Debug {
+pub fn play_interactive<T: Game<Player = impl TwoPlayer + Debug + 'static> + Display>(mut game: T)
+where
+    <T as Game>::Move: FromStr + Debug,
+    <<T as Game>::Move as FromStr>::Err: Debug,
+{
     loop {
         print!("{}", game);
         println!();
@@ -29,10 +31,13 @@ pub fn play_interactive<
             Ok(game_move) => {
                 if let Err(err) = move_failable(&mut game, &game_move) {
                     clearscreen::clear().expect("failed to clear screen");
-                    println!("{}", format!("Failed to make move {game_move:?}: {err:?}").red());
+                    println!(
+                        "{}",
+                        format!("Failed to make move {game_move:?}: {err:?}").red()
+                    );
                     continue;
                 }
-            },
+            }
             Err(err) => {
                 clearscreen::clear().expect("failed to clear screen");
                 println!("{}", format!("Invalid move {game_move}: {err:?}").red());
@@ -14,10 +14,13 @@ where
         match err {
             GameSolveError::MoveError(err) => {
                 eprintln!("Error making move: {:?}", err);
-            },
+            }
             GameSolveError::Cancelled => {
                 eprintln!("Game solving was cancelled!");
-            },
+            }
+            GameSolveError::UnboundedGame => {
+                eprintln!("This game has no bound on its amount of moves, so it can't be solved.");
+            }
         }
         vec![]
     });
@@ -26,19 +29,20 @@ where
     for (game_move, score) in move_scores {
         if current_move_score != Some(score) {
             match score_to_outcome(game, score) {
-                GameScoreOutcome::Win(moves) => println!(
+                Some(GameScoreOutcome::Win(moves)) => println!(
                     "\n\nWin in {} move{} (score {}):",
                     moves,
                     if moves == 1 { "" } else { "s" },
                     score
                 ),
-                GameScoreOutcome::Loss(moves) => println!(
+                Some(GameScoreOutcome::Loss(moves)) => println!(
                     "\n\nLose in {} move{} (score {}):",
                     moves,
                     if moves == 1 { "" } else { "s" },
                     score
                 ),
-                GameScoreOutcome::Tie => println!("\n\nTie with the following moves:"),
+                Some(GameScoreOutcome::Tie) => println!("\n\nTie with the following moves:"),
+                None => println!("\n\nScore {}:", score),
             }
             current_move_score = Some(score);
         }
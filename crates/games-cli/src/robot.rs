@@ -3,12 +3,12 @@ use game_solver::{
     par_move_scores,
     player::{ImpartialPlayer, TwoPlayer},
 };
-use tokio_util::sync::CancellationToken;
 use std::{
     any::TypeId,
     fmt::{Debug, Display},
     hash::Hash,
 };
+use tokio_util::sync::CancellationToken;
 
 use crate::report::scores::show_scores;
 
@@ -0,0 +1,72 @@
+//! Baseline benchmarks for the solver, on pinned Connect Four positions of varying difficulty,
+//! and on a small Reversi board.
+//!
+//! Run with `cargo bench -p games --features bench`.
+
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use game_solver::{game::Game, move_scores, par_move_scores, solve, GameSolveError};
+use games::{connect_four::ConnectFour, reversi::Reversi, util::move_natural::NaturalMove};
+
+/// Plays `moves` (a string of column indices) from the empty board.
+fn play<const WIDTH: usize, const HEIGHT: usize>(moves: &str) -> ConnectFour<WIDTH, HEIGHT> {
+    let mut game = ConnectFour::new();
+    for column in moves.chars() {
+        let column = column.to_digit(10).unwrap() as usize;
+        game.make_move(&NaturalMove([column])).unwrap();
+    }
+    game
+}
+
+fn bench_position<const WIDTH: usize, const HEIGHT: usize>(
+    c: &mut Criterion,
+    name: &str,
+    moves: &str,
+) {
+    let game = play::<WIDTH, HEIGHT>(moves);
+
+    c.bench_function(&format!("solve {name}"), |b| {
+        b.iter(|| solve(&game, &mut HashMap::new(), None).unwrap())
+    });
+
+    c.bench_function(&format!("move_scores {name}"), |b| {
+        b.iter(|| {
+            move_scores(&game, &mut HashMap::new(), None)
+                .collect::<Result<Vec<_>, GameSolveError<_>>>()
+                .unwrap()
+        })
+    });
+
+    let mut group = c.benchmark_group(format!("par_move_scores {name}"));
+    for threads in [1, 2, 4] {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(threads)
+            .build()
+            .unwrap();
+
+        group.bench_with_input(BenchmarkId::from_parameter(threads), &game, |b, game| {
+            b.to_async(&runtime)
+                .iter(|| par_move_scores(game, None, None))
+        });
+    }
+    group.finish();
+}
+
+fn connect_four(c: &mut Criterion) {
+    bench_position::<4, 4>(c, "4x4 empty", "");
+    bench_position::<5, 4>(c, "5x4 empty", "");
+    bench_position::<7, 6>(c, "7x6 endgame", "3332224441155501");
+}
+
+fn reversi(c: &mut Criterion) {
+    // (larger boards are far out of reach of the solver)
+    let game = Reversi::<4, 4>::new();
+
+    c.bench_function("solve reversi 4x4 opening", |b| {
+        b.iter(|| solve(&game, &mut HashMap::new(), None).unwrap())
+    });
+}
+
+criterion_group!(benches, connect_four, reversi);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+//! Compares the two-level transposition table to tables of the same size with a single
+//! replacement policy, on Connect Four positions with more boards than fit in the tables.
+//!
+//! Besides the timings, the cache hits of each table are printed before benchmarking.
+//!
+//! Run with `cargo bench -p games --features bench --bench transposition`.
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use game_solver::{
+    bench::GameBenchmark,
+    config::SolverConfig,
+    game::Game,
+    solve,
+    transposition::{Score, TranspositionTable, TwoLevelTranspositionTable},
+};
+use games::{connect_four::ConnectFour, util::move_natural::NaturalMove};
+
+/// The amount of boards every table can hold.
+const CAPACITY: usize = 1 << 12;
+
+/// A table with one board per bucket, which is either always replaced,
+/// or only replaced by boards with at least as many remaining moves (depth-preferred).
+struct SingleSlotTable<T> {
+    slots: Vec<Option<(T, Score, usize)>>,
+    depth_preferred: bool,
+}
+
+impl<T> SingleSlotTable<T> {
+    fn new(depth_preferred: bool) -> Self {
+        Self {
+            slots: (0..CAPACITY).map(|_| None).collect(),
+            depth_preferred,
+        }
+    }
+}
+
+impl<T: Game + Hash + Eq> SingleSlotTable<T> {
+    fn slot(&self, board: &T) -> usize {
+        let mut hasher = DefaultHasher::new();
+        board.hash(&mut hasher);
+        (hasher.finish() % self.slots.len() as u64) as usize
+    }
+}
+
+impl<T: Game + Hash + Eq> TranspositionTable<T> for SingleSlotTable<T> {
+    fn get(&self, board: &T) -> Option<Score> {
+        match &self.slots[self.slot(board)] {
+            Some((stored, score, _)) if stored == board => Some(*score),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, board: T, score: Score) {
+        let depth = board.max_moves().unwrap() - board.move_count();
+        let slot = self.slot(&board);
+
+        let keep = self.depth_preferred
+            && self.slots[slot]
+                .as_ref()
+                .is_some_and(|(_, _, existing)| depth < *existing);
+        if !keep {
+            self.slots[slot] = Some((board, score, depth));
+        }
+    }
+
+    fn has(&self, board: &T) -> bool {
+        self.get(board).is_some()
+    }
+}
+
+const TABLES: [&str; 3] = ["always replace", "depth-preferred", "two-level"];
+
+fn table(name: &str) -> Box<dyn TranspositionTable<ConnectFour<5, 4>>> {
+    match name {
+        "always replace" => Box::new(SingleSlotTable::new(false)),
+        "depth-preferred" => Box::new(SingleSlotTable::new(true)),
+        // two boards per bucket.
+        "two-level" => Box::new(TwoLevelTranspositionTable::with_capacity(CAPACITY / 2)),
+        _ => unreachable!(),
+    }
+}
+
+fn two_level(c: &mut Criterion) {
+    let mut game = ConnectFour::<5, 4>::new();
+    game.make_move(&NaturalMove([2])).unwrap();
+
+    let mut benchmark = GameBenchmark::new(game.clone());
+    for name in TABLES {
+        benchmark
+            .run_with_table(name, SolverConfig::new(), table(name).as_mut())
+            .unwrap();
+    }
+    println!("{}", benchmark.report());
+
+    let mut group = c.benchmark_group("transposition tables 5x4");
+    for name in TABLES {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || table(name),
+                |mut table| solve(&game, table.as_mut(), None).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.bench_function("unbounded", |b| {
+        b.iter(|| solve(&game, &mut HashMap::new(), None).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, two_level);
+criterion_main!(benches);
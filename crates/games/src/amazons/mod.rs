@@ -0,0 +1,569 @@
+#![doc = include_str!("./README.md")]
+
+use anyhow::{anyhow, Error};
+use clap::Args;
+use game_solver::{
+    game::{Game, GameState},
+    player::{PartizanPlayer, Player},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+    str::FromStr,
+};
+use thiserror::Error;
+
+use crate::util::move_failable;
+
+/// The eight directions amazons (and their arrows) can travel in.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// A square on the board, as (column, row), where (0, 0) is the bottom left.
+pub type Square = (usize, usize);
+
+/// Formats a square like a chess square, e.g. (0, 0) is `a1`.
+fn format_square((x, y): Square) -> String {
+    format!("{}{}", (b'a' + x as u8) as char, y + 1)
+}
+
+fn parse_square(s: &str) -> Result<Square, Error> {
+    let mut chars = s.chars();
+    let column = chars
+        .next()
+        .filter(char::is_ascii_lowercase)
+        .ok_or_else(|| anyhow!("{s} should start with a column letter"))?;
+    let row = chars
+        .as_str()
+        .parse::<usize>()
+        .ok()
+        .filter(|&row| row > 0)
+        .ok_or_else(|| anyhow!("{s} should end with a row number, starting from 1"))?;
+
+    Ok(((column as u8 - b'a') as usize, row - 1))
+}
+
+/// Moving an amazon `from` one square `to` another, then shooting an `arrow` from there.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
+pub struct AmazonsMove {
+    pub from: Square,
+    pub to: Square,
+    pub arrow: Square,
+}
+
+impl Display for AmazonsMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            format_square(self.from),
+            format_square(self.to),
+            format_square(self.arrow)
+        )
+    }
+}
+
+impl FromStr for AmazonsMove {
+    type Err = Error;
+
+    /// Parses a move written as `from-to-arrow`, e.g. `a1-a3-c3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let squares = s
+            .split('-')
+            .map(parse_square)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match squares[..] {
+            [from, to, arrow] => Ok(Self { from, to, arrow }),
+            _ => Err(anyhow!("{s} should have three squares, e.g. a1-a3-c3")),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum AmazonsMoveError {
+    #[error("there is no amazon of {player:?} on {}.", format_square(*square))]
+    NoAmazon {
+        square: Square,
+        player: PartizanPlayer,
+    },
+    #[error("the amazon on {} can't move to {}.", format_square(*from), format_square(*to))]
+    BlockedAmazon { from: Square, to: Square },
+    #[error("an arrow can't be shot from {} to {}.", format_square(*from), format_square(*to))]
+    BlockedArrow { from: Square, to: Square },
+}
+
+/// A game of Amazons on a board of up to 128 squares,
+/// where every kind of piece is a bitboard (indexed by `row * WIDTH + column`).
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Amazons<const WIDTH: usize, const HEIGHT: usize> {
+    /// The amazons of the player who moves first
+    left: u128,
+    /// The amazons of the player who moves second
+    right: u128,
+    /// Squares blocked by arrows
+    arrows: u128,
+    move_count: usize,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for Amazons<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Amazons<WIDTH, HEIGHT> {
+    /// A board with one amazon for each player, in opposite corners:
+    /// the first player on the bottom left, and the second player on the top right.
+    pub fn new() -> Self {
+        Self::with_amazons(&[(0, 0)], &[(WIDTH - 1, HEIGHT - 1)])
+    }
+
+    /// A board with amazons on the given squares, for the player who moves first (`left`)
+    /// and the player who moves second (`right`).
+    ///
+    /// # Panics
+    ///
+    /// If the board has more than 128 squares, or a square is off the board or used twice.
+    pub fn with_amazons(left: &[Square], right: &[Square]) -> Self {
+        assert!(WIDTH * HEIGHT <= 128, "boards can have at most 128 squares");
+
+        let mut game = Self {
+            left: 0,
+            right: 0,
+            arrows: 0,
+            move_count: 0,
+        };
+
+        for (&square, player) in left
+            .iter()
+            .map(|square| (square, PartizanPlayer::Left))
+            .chain(right.iter().map(|square| (square, PartizanPlayer::Right)))
+        {
+            assert!(
+                square.0 < WIDTH && square.1 < HEIGHT,
+                "amazons must be on the board"
+            );
+            let bit = Self::bit(square);
+            assert!(game.occupied() & bit == 0, "amazons can't share a square");
+
+            *game.amazons_mut(player) |= bit;
+        }
+
+        game
+    }
+
+    fn bit((x, y): Square) -> u128 {
+        1 << (y * WIDTH + x)
+    }
+
+    fn square(index: usize) -> Square {
+        (index % WIDTH, index / WIDTH)
+    }
+
+    fn occupied(&self) -> u128 {
+        self.left | self.right | self.arrows
+    }
+
+    fn amazons(&self, player: PartizanPlayer) -> u128 {
+        match player {
+            PartizanPlayer::Left => self.left,
+            PartizanPlayer::Right => self.right,
+        }
+    }
+
+    fn amazons_mut(&mut self, player: PartizanPlayer) -> &mut u128 {
+        match player {
+            PartizanPlayer::Left => &mut self.left,
+            PartizanPlayer::Right => &mut self.right,
+        }
+    }
+
+    /// The square one step from `square` in `direction`, if it's on the board.
+    fn step((x, y): Square, (dx, dy): (isize, isize)) -> Option<Square> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < WIDTH)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < HEIGHT)?;
+        Some((x, y))
+    }
+
+    /// Every empty square a queen on `from` can reach, given the `occupied` squares.
+    fn reachable(from: Square, occupied: u128) -> impl Iterator<Item = Square> {
+        DIRECTIONS.into_iter().flat_map(move |direction| {
+            std::iter::successors(Self::step(from, direction), move |&square| {
+                Self::step(square, direction)
+            })
+            .take_while(move |&square| occupied & Self::bit(square) == 0)
+        })
+    }
+
+    /// Returns true if a queen on `from` can reach `to`, given the `occupied` squares.
+    fn can_reach(from: Square, to: Square, occupied: u128) -> bool {
+        to.0 < WIDTH && to.1 < HEIGHT && Self::reachable(from, occupied).any(|square| square == to)
+    }
+
+    /// Returns true if `player` can't move any of their amazons.
+    ///
+    /// An amazon can always shoot back to the square it came from,
+    /// so a player can move as long as one of their amazons has an empty neighbor.
+    fn is_stuck(&self, player: PartizanPlayer) -> bool {
+        let occupied = self.occupied();
+        let amazons = self.amazons(player);
+
+        (0..WIDTH * HEIGHT)
+            .filter(|&index| amazons & (1 << index) != 0)
+            .all(|index| {
+                DIRECTIONS.into_iter().all(|direction| {
+                    Self::step(Self::square(index), direction)
+                        .map_or(true, |square| occupied & Self::bit(square) != 0)
+                })
+            })
+    }
+
+    /// Applies `transform` (which maps squares to squares) to every square of `board`.
+    fn transform_board(board: u128, transform: impl Fn(Square) -> Square) -> u128 {
+        (0..WIDTH * HEIGHT)
+            .filter(|&index| board & (1 << index) != 0)
+            .fold(0, |transformed, index| {
+                transformed | Self::bit(transform(Self::square(index)))
+            })
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Game for Amazons<WIDTH, HEIGHT> {
+    type Move = AmazonsMove;
+    type Iter<'a> = std::vec::IntoIter<Self::Move>;
+    type Player = PartizanPlayer;
+    type MoveError = AmazonsMoveError;
+
+    fn max_moves(&self) -> Option<usize> {
+        // every move blocks exactly one empty square with an arrow
+        Some(self.move_count + WIDTH * HEIGHT - self.occupied().count_ones() as usize)
+    }
+
+    fn move_count(&self) -> usize {
+        self.move_count
+    }
+
+    fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+        let player = self.player();
+
+        if m.from.0 >= WIDTH || m.from.1 >= HEIGHT || self.amazons(player) & Self::bit(m.from) == 0
+        {
+            return Err(AmazonsMoveError::NoAmazon {
+                square: m.from,
+                player,
+            });
+        }
+
+        if !Self::can_reach(m.from, m.to, self.occupied()) {
+            return Err(AmazonsMoveError::BlockedAmazon {
+                from: m.from,
+                to: m.to,
+            });
+        }
+
+        let occupied = self.occupied() & !Self::bit(m.from) | Self::bit(m.to);
+        if !Self::can_reach(m.to, m.arrow, occupied) {
+            return Err(AmazonsMoveError::BlockedArrow {
+                from: m.to,
+                to: m.arrow,
+            });
+        }
+
+        let amazons = self.amazons_mut(player);
+        *amazons = *amazons & !Self::bit(m.from) | Self::bit(m.to);
+        self.arrows |= Self::bit(m.arrow);
+        self.move_count += 1;
+
+        Ok(())
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        let amazons = self.amazons(self.player());
+        let mut moves = Vec::new();
+
+        for from in (0..WIDTH * HEIGHT).filter(|&index| amazons & (1 << index) != 0) {
+            let from = Self::square(from);
+            // the amazon leaves its square, so arrows can be shot through (or onto) it
+            let without_amazon = self.occupied() & !Self::bit(from);
+
+            for to in Self::reachable(from, self.occupied()) {
+                for arrow in Self::reachable(to, without_amazon | Self::bit(to)) {
+                    moves.push(AmazonsMove { from, to, arrow });
+                }
+            }
+        }
+
+        moves.into_iter()
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        // the last player to move wins, like in every normal play game
+        if self.is_stuck(self.player()) {
+            GameState::Win(self.player().previous())
+        } else {
+            GameState::Playable
+        }
+    }
+
+    fn player(&self) -> Self::Player {
+        PartizanPlayer::from_move_count(self.move_count)
+    }
+
+    fn canonical_form(&self) -> Self {
+        let (width, height) = (WIDTH - 1, HEIGHT - 1);
+        let mut symmetries: Vec<Box<dyn Fn(Square) -> Square>> = vec![
+            Box::new(|(x, y)| (x, y)),
+            Box::new(move |(x, y)| (width - x, y)),
+            Box::new(move |(x, y)| (x, height - y)),
+            Box::new(move |(x, y)| (width - x, height - y)),
+        ];
+
+        // square boards can also be reflected along their diagonals
+        if WIDTH == HEIGHT {
+            symmetries.extend::<[Box<dyn Fn(Square) -> Square>; 4]>([
+                Box::new(|(x, y)| (y, x)),
+                Box::new(move |(x, y)| (width - y, x)),
+                Box::new(move |(x, y)| (y, height - x)),
+                Box::new(move |(x, y)| (width - y, height - x)),
+            ]);
+        }
+
+        symmetries
+            .iter()
+            .map(|symmetry| Self {
+                left: Self::transform_board(self.left, symmetry),
+                right: Self::transform_board(self.right, symmetry),
+                arrows: Self::transform_board(self.arrows, symmetry),
+                move_count: self.move_count,
+            })
+            .min_by_key(|game| (game.left, game.right, game.arrows))
+            .unwrap()
+    }
+
+    fn display_move(&self, m: &Self::Move) -> String {
+        m.to_string()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Display for Amazons<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in (0..HEIGHT).rev() {
+            write!(f, "{:>2} ", y + 1)?;
+            for x in 0..WIDTH {
+                let bit = Self::bit((x, y));
+                let square = if self.left & bit != 0 {
+                    'L'
+                } else if self.right & bit != 0 {
+                    'R'
+                } else if self.arrows & bit != 0 {
+                    'X'
+                } else {
+                    '.'
+                };
+                write!(f, "{square}")?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "   ")?;
+        for x in 0..WIDTH {
+            write!(f, "{}", (b'a' + x as u8) as char)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Debug for Amazons<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        <Self as Display>::fmt(self, f)
+    }
+}
+
+/// Analyzes the Game of the Amazons.
+///
+#[doc = include_str!("./README.md")]
+#[derive(Args, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+pub struct AmazonsArgs {
+    /// Amazons moves, written as `from-to-arrow`, e.g. a1-a3-c3 ...
+    moves: Vec<String>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<AmazonsArgs> for Amazons<WIDTH, HEIGHT> {
+    type Error = Error;
+
+    fn try_from(args: AmazonsArgs) -> Result<Self, Self::Error> {
+        let mut game = Amazons::new();
+
+        // parse every move in args, e.g. a1-a3-c3 in args
+        for arg in args.moves {
+            move_failable(&mut game, &AmazonsMove::from_str(&arg)?)?;
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use game_solver::{solve, transposition::SymmetryAwareTable};
+
+    use super::*;
+    use crate::util::reference_score;
+
+    fn play<const WIDTH: usize, const HEIGHT: usize>(
+        game: &mut Amazons<WIDTH, HEIGHT>,
+        moves: &[&str],
+    ) {
+        for m in moves {
+            game.make_move(&m.parse().unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn moves_round_trip() {
+        let m = "a1-a3-c3".parse::<AmazonsMove>().unwrap();
+        assert_eq!(
+            m,
+            AmazonsMove {
+                from: (0, 0),
+                to: (0, 2),
+                arrow: (2, 2)
+            }
+        );
+        assert_eq!(m.to_string(), "a1-a3-c3");
+
+        assert!("a1-a3".parse::<AmazonsMove>().is_err());
+        assert!("a0-a3-c3".parse::<AmazonsMove>().is_err());
+        assert!("11-a3-c3".parse::<AmazonsMove>().is_err());
+    }
+
+    #[test]
+    fn illegal_moves() {
+        let mut game = Amazons::<4, 4>::new();
+
+        // the second player's amazon
+        assert!(matches!(
+            game.make_move(&"d4-d3-d2".parse().unwrap()),
+            Err(AmazonsMoveError::NoAmazon { .. })
+        ));
+        // not a queen move
+        assert!(matches!(
+            game.make_move(&"a1-b3-b4".parse().unwrap()),
+            Err(AmazonsMoveError::BlockedAmazon { .. })
+        ));
+
+        play(&mut game, &["a1-a4-a1"]);
+        // the arrow would land on the other amazon
+        assert!(matches!(
+            game.make_move(&"d4-d1-a4".parse().unwrap()),
+            Err(AmazonsMoveError::BlockedArrow { .. })
+        ));
+    }
+
+    #[test]
+    fn possible_moves_are_legal() {
+        let mut game = Amazons::<4, 4>::new();
+        play(&mut game, &["a1-b2-a1"]);
+
+        let moves = game.possible_moves().collect::<Vec<_>>();
+        assert!(!moves.is_empty());
+        for m in moves {
+            assert!(game.clone().make_move(&m).is_ok(), "{m} should be legal");
+        }
+
+        // the amazon can shoot back to the square it left
+        assert!(game
+            .possible_moves()
+            .any(|m| m.from == (3, 3) && m.arrow == (3, 3)));
+    }
+
+    #[test]
+    fn stuck_player_loses() {
+        let mut game = Amazons::<3, 1>::new();
+        assert_eq!(game.state(), GameState::Playable);
+
+        // the second player's amazon is left with no empty neighbors
+        play(&mut game, &["a1-b1-a1"]);
+
+        assert!(game.possible_moves().next().is_none());
+        assert_eq!(game.state(), GameState::Win(PartizanPlayer::Left));
+    }
+
+    #[test]
+    fn canonical_forms_match_under_symmetry() {
+        let mut corner = Amazons::<4, 4>::new();
+        play(&mut corner, &["a1-b2-c3"]);
+
+        // the same move, reflected from left to right
+        let mut reflected = Amazons::<4, 4>::with_amazons(&[(3, 0)], &[(0, 3)]);
+        play(&mut reflected, &["d1-c2-b3"]);
+
+        assert_ne!(corner, reflected);
+        assert_eq!(corner.canonical_form(), reflected.canonical_form());
+    }
+
+    fn assert_matches_reference<const WIDTH: usize, const HEIGHT: usize>(
+        game: Amazons<WIDTH, HEIGHT>,
+    ) {
+        let expected = reference_score(&game);
+
+        assert_eq!(solve(&game, &mut HashMap::new(), None).unwrap(), expected);
+        assert_eq!(
+            solve(&game, &mut SymmetryAwareTable::new(HashMap::new()), None).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn matches_reference() {
+        assert_matches_reference(Amazons::<3, 3>::new());
+        assert_matches_reference(Amazons::<3, 3>::with_amazons(&[(1, 1)], &[(0, 0)]));
+        assert_matches_reference(Amazons::<4, 3>::new());
+        assert_matches_reference(Amazons::<3, 3>::with_amazons(&[(0, 0), (2, 2)], &[(1, 1)]));
+    }
+
+    /// A single row, with the first player's amazon on the left end, the second player's amazon
+    /// on the right end, arrows on `arrows` (as indices), and `player` to move.
+    fn row<const WIDTH: usize>(arrows: &[usize], player: PartizanPlayer) -> Amazons<WIDTH, 1> {
+        let mut game = Amazons::with_amazons(&[(0, 0)], &[(WIDTH - 1, 0)]);
+        for &arrow in arrows {
+            game.arrows |= Amazons::<WIDTH, 1>::bit((arrow, 0));
+        }
+        game.move_count = player.idx();
+        game
+    }
+
+    #[test]
+    fn known_values() {
+        // in combinatorial game theory (e.g. in Lessons in Play, by Albert, Nowakowski and Wolfe),
+        // an amazon alone in a corridor of k empty squares is worth the integer k,
+        // and positions split by arrows are sums of their parts.
+        let wins = |game: &Amazons<8, 1>| solve(game, &mut HashMap::new(), None).unwrap() > 0;
+
+        // 3 - 2 = 1: the first player wins, whoever moves first.
+        assert!(wins(&row(&[4], PartizanPlayer::Left)));
+        assert!(!wins(&row(&[4], PartizanPlayer::Right)));
+
+        // 2 - 2 = 0: whoever moves first loses.
+        assert!(!wins(&row(&[3, 4], PartizanPlayer::Left)));
+        assert!(!wins(&row(&[3, 4], PartizanPlayer::Right)));
+
+        // two amazons on either side of one empty square are worth *: whoever moves first wins.
+        for player in [PartizanPlayer::Left, PartizanPlayer::Right] {
+            let game = row::<3>(&[], player);
+            assert!(solve(&game, &mut HashMap::new(), None).unwrap() > 0);
+        }
+    }
+}
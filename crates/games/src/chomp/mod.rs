@@ -125,7 +125,7 @@ impl Game for Chomp {
     }
 
     fn state(&self) -> GameState<Self::Player> {
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 }
 
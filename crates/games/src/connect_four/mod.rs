@@ -0,0 +1,617 @@
+#![doc = include_str!("./README.md")]
+
+#[cfg(feature = "egui")]
+pub mod gui;
+
+use anyhow::Error;
+use clap::Args;
+use game_solver::{
+    game::{max_score, ExactGame, Game, GameState},
+    player::PartizanPlayer,
+};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+};
+use thiserror::Error;
+
+use crate::util::{move_failable, move_natural::NaturalMove};
+
+/// The column to drop a piece into.
+pub type ConnectFourMove = NaturalMove<1>;
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct ConnectFour<const WIDTH: usize, const HEIGHT: usize> {
+    /// Every column, from the bottom up. None if empty, Some(Player) if occupied
+    board: [[Option<PartizanPlayer>; HEIGHT]; WIDTH],
+    /// The amount of pieces in every column
+    heights: [usize; WIDTH],
+    move_count: usize,
+    /// The player that connected four, if any
+    winner: Option<PartizanPlayer>,
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum ConnectFourMoveError {
+    #[error("column {column} is out of bounds of the board's width {width}.")]
+    ColumnOutOfBounds { column: usize, width: usize },
+    #[error("column {0} is already full.")]
+    FullColumn(usize),
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for ConnectFour<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> ConnectFour<WIDTH, HEIGHT> {
+    pub fn new() -> Self {
+        Self {
+            board: [[None; HEIGHT]; WIDTH],
+            heights: [0; WIDTH],
+            move_count: 0,
+            winner: None,
+        }
+    }
+
+    /// Returns true if a piece of `player` at (`column`, `row`) would be part of four in a row.
+    fn connects(&self, column: usize, row: usize, player: PartizanPlayer) -> bool {
+        let directions: &[(isize, isize)] = &[(1, 0), (0, 1), (1, 1), (1, -1)];
+
+        directions.iter().any(|&(x_dir, y_dir)| {
+            // the piece itself, and every piece of the same player on both sides of it
+            1 + self.count_line(column, row, x_dir, y_dir, player)
+                + self.count_line(column, row, -x_dir, -y_dir, player)
+                >= 4
+        })
+    }
+
+    /// Counts the pieces of `player` in a line starting next to (`column`, `row`).
+    fn count_line(
+        &self,
+        column: usize,
+        row: usize,
+        x_dir: isize,
+        y_dir: isize,
+        player: PartizanPlayer,
+    ) -> usize {
+        let mut count = 0;
+        let (mut x, mut y) = (column, row);
+
+        while let (Some(new_x), Some(new_y)) =
+            (x.checked_add_signed(x_dir), y.checked_add_signed(y_dir))
+        {
+            if new_x >= WIDTH || new_y >= HEIGHT || self.board[new_x][new_y] != Some(player) {
+                break;
+            }
+
+            count += 1;
+            (x, y) = (new_x, new_y);
+        }
+
+        count
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> ExactGame for ConnectFour<WIDTH, HEIGHT> {}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Game for ConnectFour<WIDTH, HEIGHT> {
+    type Move = ConnectFourMove;
+    type Iter<'a> = std::vec::IntoIter<Self::Move>;
+    type Player = PartizanPlayer;
+    type MoveError = ConnectFourMoveError;
+
+    fn max_moves(&self) -> Option<usize> {
+        Some(WIDTH * HEIGHT)
+    }
+
+    fn move_count(&self) -> usize {
+        self.move_count
+    }
+
+    fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
+        let [column] = m.0;
+
+        if column >= WIDTH {
+            return Err(ConnectFourMoveError::ColumnOutOfBounds {
+                column,
+                width: WIDTH,
+            });
+        }
+
+        let row = self.heights[column];
+        if row >= HEIGHT {
+            return Err(ConnectFourMoveError::FullColumn(column));
+        }
+
+        let player = self.player();
+        if self.connects(column, row, player) {
+            self.winner = Some(player);
+        }
+
+        self.board[column][row] = Some(player);
+        self.heights[column] += 1;
+        self.move_count += 1;
+
+        Ok(())
+    }
+
+    fn possible_moves(&self) -> Self::Iter<'_> {
+        if self.winner.is_some() {
+            return Vec::new().into_iter();
+        }
+
+        // center columns first, since they are part of the most lines
+        (0..WIDTH)
+            .filter(|&column| self.heights[column] < HEIGHT)
+            .sorted_by_key(|&column| (2 * column).abs_diff(WIDTH - 1))
+            .map(|column| NaturalMove([column]))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn find_immediately_resolvable_game_among(
+        &self,
+        moves: &[Self::Move],
+    ) -> Result<Option<Self>, Self::MoveError> {
+        if self.winner.is_some() {
+            return Ok(None);
+        }
+
+        // a move can never lose the game for the player who made it,
+        // so the only resolvable games are wins - or a tie, if this is the last move.
+        if let Some(column) =
+            (0..WIDTH).find(|&column| self.is_winning_move(&NaturalMove([column])))
+        {
+            let mut board = self.clone();
+            board.make_move(&NaturalMove([column]))?;
+            return Ok(Some(board));
+        }
+
+        if self.move_count + 1 == WIDTH * HEIGHT {
+            let mut board = self.clone();
+            board.make_move(&moves[0])?;
+            return Ok(Some(board));
+        }
+
+        Ok(None)
+    }
+
+    /// Checks if the current player connects four by dropping a piece in the column,
+    /// without dropping it.
+    fn is_winning_move(&self, m: &Self::Move) -> bool {
+        let column = m.0[0];
+        column < WIDTH
+            && self.heights[column] < HEIGHT
+            && self.connects(column, self.heights[column], self.player())
+    }
+
+    fn score_after_winning_move(&self, m: &Self::Move) -> Option<isize> {
+        if self.is_winning_move(m) {
+            max_score(self).map(|score| score - 1)
+        } else {
+            None
+        }
+    }
+
+    fn move_to_index(&self, m: &Self::Move) -> Option<usize> {
+        (m.0[0] < WIDTH).then_some(m.0[0])
+    }
+
+    fn move_from_index(&self, index: usize) -> Option<Self::Move> {
+        (index < WIDTH).then_some(NaturalMove([index]))
+    }
+
+    fn move_to_index_count(&self) -> Option<usize> {
+        Some(WIDTH)
+    }
+
+    fn is_draw(&self) -> bool {
+        self.winner.is_none() && self.move_count == WIDTH * HEIGHT
+    }
+
+    fn display_move(&self, m: &Self::Move) -> String {
+        format!("column {}", m.0[0])
+    }
+
+    fn state(&self) -> GameState<Self::Player> {
+        if let Some(winner) = self.winner {
+            return GameState::Win(winner);
+        }
+
+        if self.move_count == WIDTH * HEIGHT {
+            return GameState::Tie;
+        }
+
+        GameState::Playable
+    }
+
+    fn player(&self) -> Self::Player {
+        PartizanPlayer::from_move_count(self.move_count)
+    }
+}
+
+fn player_to_char(player: Option<PartizanPlayer>) -> char {
+    match player {
+        Some(PartizanPlayer::Left) => 'X',
+        Some(PartizanPlayer::Right) => 'O',
+        None => '.',
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Display for ConnectFour<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in (0..HEIGHT).rev() {
+            for column in 0..WIDTH {
+                write!(f, "{}", player_to_char(self.board[column][row]))?;
+            }
+            writeln!(f)?;
+        }
+
+        for column in 0..WIDTH {
+            write!(f, "{}", column % 10)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Debug for ConnectFour<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        <Self as Display>::fmt(self, f)
+    }
+}
+
+/// Analyzes Connect Four.
+///
+#[doc = include_str!("./README.md")]
+#[derive(Args, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+pub struct ConnectFourArgs {
+    /// Connect Four moves, ordered as the columns to drop a piece in, e.g. 3 3 4 ...
+    #[arg(value_parser = clap::value_parser!(ConnectFourMove))]
+    moves: Vec<ConnectFourMove>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<ConnectFourArgs>
+    for ConnectFour<WIDTH, HEIGHT>
+{
+    type Error = Error;
+
+    fn try_from(args: ConnectFourArgs) -> Result<Self, Self::Error> {
+        let mut game = ConnectFour::new();
+
+        // parse every move in args, e.g. 3 3 4 in args
+        for game_move in args.moves {
+            move_failable(&mut game, &game_move)?;
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::atomic::Ordering};
+
+    use game_solver::{
+        config::SolverConfig,
+        game::upper_bound,
+        heuristic::{HistoryTable, MoveOrderer, WeightedMoveOrderer},
+        move_scores, move_scores_ranked, opening_moves, ranked_moves, solve, solve_with_config,
+        stats::Stats,
+        util::{perft, perft_hash},
+    };
+
+    use super::*;
+    use crate::util::reference_score;
+
+    /// Plays a sequence of columns, written as a string of digits (e.g. "3342").
+    fn play<const WIDTH: usize, const HEIGHT: usize>(moves: &str) -> ConnectFour<WIDTH, HEIGHT> {
+        let mut game = ConnectFour::new();
+        for column in moves.chars() {
+            let column = column.to_digit(10).unwrap() as usize;
+            game.make_move(&NaturalMove([column])).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn perft_fingerprints() {
+        const PERFT: [u64; 5] = [7, 49, 343, 2401, 16807];
+        const FINGERPRINTS: [u64; 5] = [
+            0xfab7_6bb1_da04_2b9a,
+            0xd005_434e_572f_8de0,
+            0x61b9_b010_0140_b7fc,
+            0x1142_a78e_ffeb_8f1c,
+            0x6284_112c_f7c5_cf3a,
+        ];
+
+        let game = ConnectFour::<7, 6>::new();
+        for depth in 1..=5 {
+            assert_eq!(perft(&game, depth), PERFT[depth - 1], "depth {depth}");
+            assert_eq!(
+                perft_hash(&game, depth),
+                FINGERPRINTS[depth - 1],
+                "depth {depth}"
+            );
+        }
+    }
+
+    #[test]
+    fn vertical_win() {
+        let game = play::<7, 6>("0101010");
+        assert_eq!(game.state(), GameState::Win(PartizanPlayer::Left));
+    }
+
+    #[test]
+    fn horizontal_win() {
+        let game = play::<7, 6>("0011223");
+        assert_eq!(game.state(), GameState::Win(PartizanPlayer::Left));
+    }
+
+    #[test]
+    fn diagonal_win() {
+        // X fills in the diagonal from (0, 0) to (3, 3) on its last move
+        let game = play::<7, 6>("0112322363");
+        assert_eq!(game.state(), GameState::Playable);
+
+        let game = play::<7, 6>("01123223633");
+        assert_eq!(game.state(), GameState::Win(PartizanPlayer::Left));
+    }
+
+    #[test]
+    fn full_columns() {
+        let mut game = play::<7, 6>("000000");
+        assert!(game.possible_moves().all(|m| m.0 != [0]));
+        assert!(game.make_move(&NaturalMove([0])).is_err());
+        assert!(game.make_move(&NaturalMove([7])).is_err());
+    }
+
+    #[test]
+    fn full_board_is_draw() {
+        // every row and column alternates in pairs, so nobody connects four
+        let game = play::<4, 4>("0101232310103232");
+        assert!(game.is_draw());
+        assert_eq!(game.state(), GameState::Tie);
+        assert!(!play::<4, 4>("010123231010323").is_draw());
+    }
+
+    #[test]
+    fn display_move() {
+        assert_eq!(
+            ConnectFour::<7, 6>::new().display_move(&NaturalMove([4])),
+            "column 4"
+        );
+    }
+
+    #[test]
+    fn immediate_win() {
+        // X has three in a column, and wins on the next move
+        let game = play::<7, 6>("010101");
+        assert_eq!(
+            solve(&game, &mut HashMap::new(), None).unwrap(),
+            upper_bound(&game).unwrap() - game.move_count() as isize
+        );
+    }
+
+    #[test]
+    fn winning_moves_match_making_them() {
+        for moves in ["", "010101", "3344556", "0123456012345", "001122"] {
+            let game = play::<7, 6>(moves);
+            for column in 0..8 {
+                let m = NaturalMove([column]);
+                let mut board = game.clone();
+                let wins =
+                    board.make_move(&m).is_ok() && board.state() == GameState::Win(game.player());
+
+                assert_eq!(game.is_winning_move(&m), wins, "{moves}, column {column}");
+            }
+        }
+    }
+
+    #[test]
+    fn winning_moves_are_scored_without_searching() {
+        let game = play::<7, 6>("010101");
+        let score = solve(&game, &mut HashMap::new(), None).unwrap();
+
+        assert_eq!(
+            game.score_after_winning_move(&NaturalMove([0])),
+            Some(score)
+        );
+        assert_eq!(game.score_after_winning_move(&NaturalMove([1])), None);
+
+        let stats = stats(&game);
+        assert_eq!(
+            solve(&game, &mut HashMap::new(), Some(&stats)).unwrap(),
+            score
+        );
+        // every probe of the root ends right away, without exploring any children
+        assert_eq!(
+            stats.states_explored.load(Ordering::Relaxed),
+            stats.terminal_ends.winning.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn opening_moves_stop_at_forced_win() {
+        let game = play::<7, 6>("010101");
+
+        // the win is found at the first depth, so there's no need to look further
+        assert_eq!(
+            opening_moves(&game, 5, &mut HashMap::new(), None).unwrap(),
+            vec![(
+                1,
+                NaturalMove([0]),
+                upper_bound(&game).unwrap() - game.move_count() as isize
+            )]
+        );
+    }
+
+    fn stats<const WIDTH: usize, const HEIGHT: usize>(
+        game: &ConnectFour<WIDTH, HEIGHT>,
+    ) -> Stats<PartizanPlayer> {
+        Stats::new(game.player(), game.move_count())
+    }
+
+    #[test]
+    fn best_ranked_move_is_lazy() {
+        let game = play::<4, 4>("1122");
+
+        let full_stats = stats(&game);
+        let ranked = move_scores_ranked(&game, &mut HashMap::new(), Some(&full_stats)).unwrap();
+
+        let lazy_stats = stats(&game);
+        let best = ranked_moves(&game, &mut HashMap::new(), Some(&lazy_stats))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(best.1, ranked[0].1);
+        // only the best move is solved exactly, so less of the tree is explored
+        assert!(
+            lazy_stats.states_explored.load(Ordering::Relaxed)
+                < full_stats.states_explored.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn history_ordering() {
+        let game = ConnectFour::<7, 6>::new();
+        for column in 0..7 {
+            let m = game.move_from_index(column).unwrap();
+            assert_eq!(game.move_to_index(&m), Some(column));
+        }
+        assert_eq!(game.move_from_index(7), None);
+
+        let mut history = HistoryTable::new(&game);
+        history.record_cutoff(&game, &NaturalMove([0]), 2);
+        history.record_cutoff(&game, &NaturalMove([6]), 3);
+        assert_eq!(history.score(&game, &NaturalMove([0])), 4);
+
+        let mut moves = game.possible_moves().collect::<Vec<_>>();
+        history.order_moves(&game, &mut moves);
+        assert_eq!(
+            &moves[..3],
+            &[NaturalMove([6]), NaturalMove([0]), NaturalMove([3])]
+        );
+
+        history.clear();
+        assert_eq!(history.score(&game, &NaturalMove([6])), 0);
+    }
+
+    #[test]
+    fn weighted_move_ordering() {
+        let game = play::<4, 4>("1122");
+        let mut map = HashMap::new();
+
+        let orderer = WeightedMoveOrderer::from_shallow_search(&game, 2, &mut map, None).unwrap();
+        assert_eq!(orderer.scores().len(), 4);
+
+        let config = SolverConfig::new().with_move_orderer(orderer);
+        assert_eq!(
+            solve_with_config(&game, &mut map, &config, None).unwrap(),
+            reference_score(&game)
+        );
+    }
+
+    #[test]
+    fn aspiration_windows() {
+        for position in ["", "0312", "3210", "01230123", "00112233"] {
+            let game = play::<4, 4>(position);
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+
+            for window in [1, 2, 4, 100] {
+                let config = SolverConfig::new().with_aspiration_window(window);
+                assert_eq!(
+                    solve_with_config(&game, &mut HashMap::new(), &config, None).unwrap(),
+                    score,
+                    "position {position}, window {window}"
+                );
+            }
+        }
+
+        // this position is a tie, so a small window around 0 finds it right away.
+        let game = play::<4, 4>("1122");
+        let (binary_stats, aspiration_stats) = (stats(&game), stats(&game));
+
+        solve(&game, &mut HashMap::new(), Some(&binary_stats)).unwrap();
+        let config = SolverConfig::new().with_aspiration_window(4);
+        solve_with_config(&game, &mut HashMap::new(), &config, Some(&aspiration_stats)).unwrap();
+
+        assert!(
+            aspiration_stats.states_explored.load(Ordering::Relaxed)
+                < binary_stats.states_explored.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn matches_reference() {
+        let positions = [
+            "01230123", "00112233", "12211221", "03300330", "11223300", "21032103", "1122", "0312",
+            "112200", "3210", "0033", "2211",
+        ];
+
+        for position in positions {
+            let game = play::<4, 4>(position);
+            assert_eq!(
+                solve(&game, &mut HashMap::new(), None).unwrap(),
+                reference_score(&game),
+                "position {position}"
+            );
+        }
+    }
+
+    /// Converts a score of [`solve`] on the standard board to the scale of Pascal Pons' solver
+    /// (<http://blog.gamesolver.org>), where winning with your last stone scores 1,
+    /// and every stone left over scores one more.
+    fn pons_score(score: isize) -> isize {
+        score.signum() * ((score.abs() + 1) / 2)
+    }
+
+    #[test]
+    fn small_board_results() {
+        // from John Tromp's table of solved board sizes (<https://tromp.github.io/c4/c4.html>):
+        // these are draws, except for 6x4, which the second player wins.
+        let result = |score: isize| score.signum();
+        assert_eq!(
+            result(solve(&ConnectFour::<4, 4>::new(), &mut HashMap::new(), None).unwrap()),
+            0
+        );
+        assert_eq!(
+            result(solve(&ConnectFour::<5, 4>::new(), &mut HashMap::new(), None).unwrap()),
+            0
+        );
+        assert_eq!(
+            result(solve(&ConnectFour::<4, 5>::new(), &mut HashMap::new(), None).unwrap()),
+            0
+        );
+        assert_eq!(
+            result(solve(&ConnectFour::<6, 4>::new(), &mut HashMap::new(), None).unwrap()),
+            -1
+        );
+    }
+
+    #[test]
+    #[ignore = "solving the opening of the standard board takes hours"]
+    fn published_opening_scores() {
+        // the score of every first move, as given by Pascal Pons' solver.
+        let mut scores = move_scores(&ConnectFour::<7, 6>::new(), &mut HashMap::new(), None)
+            .map(|result| {
+                let (m, score) = result.unwrap();
+                (m.0[0], pons_score(score))
+            })
+            .collect::<Vec<_>>();
+        scores.sort();
+
+        assert_eq!(
+            scores
+                .into_iter()
+                .map(|(_, score)| score)
+                .collect::<Vec<_>>(),
+            [-2, -1, 0, 1, 0, -1, -2]
+        );
+    }
+}
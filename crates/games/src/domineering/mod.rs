@@ -12,7 +12,8 @@ use game_solver::{
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display, Formatter},
-    hash::Hash, str::FromStr,
+    hash::Hash,
+    str::FromStr,
 };
 use thiserror::Error;
 
@@ -183,15 +184,11 @@ impl<const WIDTH: usize, const HEIGHT: usize> Game for Domineering<WIDTH, HEIGHT
     }
 
     fn state(&self) -> GameState<Self::Player> {
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 
     fn player(&self) -> Self::Player {
-        if self.move_count % 2 == 0 {
-            PartizanPlayer::Left
-        } else {
-            PartizanPlayer::Right
-        }
+        PartizanPlayer::from_move_count(self.move_count)
     }
 }
 
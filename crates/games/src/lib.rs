@@ -1,6 +1,8 @@
 pub mod util;
 
+pub mod amazons;
 pub mod chomp;
+pub mod connect_four;
 pub mod domineering;
 pub mod naive_nim;
 pub mod order_and_chaos;
@@ -10,9 +12,9 @@ pub mod tic_tac_toe;
 pub mod zener;
 
 use crate::{
-    chomp::ChompArgs, domineering::DomineeringArgs, naive_nim::NimArgs,
-    order_and_chaos::OrderAndChaosArgs, reversi::ReversiArgs, sprouts::SproutsArgs,
-    tic_tac_toe::TicTacToeArgs,
+    amazons::AmazonsArgs, chomp::ChompArgs, connect_four::ConnectFourArgs,
+    domineering::DomineeringArgs, naive_nim::NimArgs, order_and_chaos::OrderAndChaosArgs,
+    reversi::ReversiArgs, sprouts::SproutsArgs, tic_tac_toe::TicTacToeArgs,
 };
 use clap::Subcommand;
 use once_cell::sync::Lazy;
@@ -29,9 +31,11 @@ pub enum Games {
     Chomp(ChompArgs),
     Sprouts(SproutsArgs),
     Zener(ZenerArgs),
+    ConnectFour(ConnectFourArgs),
+    Amazons(AmazonsArgs),
 }
 
-pub static DEFAULT_GAMES: Lazy<[Games; 8]> = Lazy::new(|| {
+pub static DEFAULT_GAMES: Lazy<[Games; 10]> = Lazy::new(|| {
     [
         Games::Reversi(Default::default()),
         Games::TicTacToe(Default::default()),
@@ -41,6 +45,8 @@ pub static DEFAULT_GAMES: Lazy<[Games; 8]> = Lazy::new(|| {
         Games::Chomp(Default::default()),
         Games::Sprouts(Default::default()),
         Games::Zener(Default::default()),
+        Games::ConnectFour(Default::default()),
+        Games::Amazons(Default::default()),
     ]
 });
 
@@ -55,6 +61,8 @@ impl Games {
             Self::Chomp(_) => "Chomp".to_string(),
             Self::Sprouts(_) => "Sprouts".to_string(),
             Self::Zener(_) => "Zener".to_string(),
+            Self::ConnectFour(_) => "Connect Four".to_string(),
+            Self::Amazons(_) => "Amazons".to_string(),
         }
     }
 
@@ -68,6 +76,8 @@ impl Games {
             Self::Chomp(_) => include_str!("./chomp/README.md"),
             Self::Sprouts(_) => include_str!("./sprouts/README.md"),
             Self::Zener(_) => include_str!("./zener/README.md"),
+            Self::ConnectFour(_) => include_str!("./connect_four/README.md"),
+            Self::Amazons(_) => include_str!("./amazons/README.md"),
         }
     }
 
@@ -123,6 +133,18 @@ impl Games {
                 &mut cache,
                 "crates/games/src/zener/README.md"
             ),
+            Self::ConnectFour(_) => egui_commonmark::commonmark_str!(
+                "connect_four",
+                ui,
+                &mut cache,
+                "crates/games/src/connect_four/README.md"
+            ),
+            Self::Amazons(_) => egui_commonmark::commonmark_str!(
+                "amazons",
+                ui,
+                &mut cache,
+                "crates/games/src/amazons/README.md"
+            ),
         };
     }
 }
@@ -5,7 +5,7 @@ pub mod gui;
 use anyhow::Error;
 use clap::Args;
 use game_solver::{
-    game::{Game, GameState, Normal, NormalImpartial},
+    game::{ExactGame, Game, GameState, Normal, NormalImpartial},
     player::ImpartialPlayer,
 };
 use serde::{Deserialize, Serialize};
@@ -17,7 +17,7 @@ use thiserror::Error;
 
 use crate::util::{move_failable, move_natural::NaturalMove};
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Nim {
     heaps: Vec<usize>,
     move_count: usize,
@@ -52,6 +52,8 @@ pub enum NimMoveError {
 }
 
 impl Normal for Nim {}
+
+impl ExactGame for Nim {}
 impl NormalImpartial for Nim {}
 impl Game for Nim {
     /// where Move is a tuple of the heap index and the number of objects to remove
@@ -108,12 +110,20 @@ impl Game for Nim {
     }
 
     fn state(&self) -> GameState<Self::Player> {
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 
     fn player(&self) -> Self::Player {
         ImpartialPlayer::Next
     }
+
+    fn terminal_game(state: GameState<Self::Player>) -> Option<Self> {
+        // Nim can't tie, and the player who made the last move always wins.
+        match state {
+            GameState::Win(ImpartialPlayer::Previous) => Some(Nim::new(vec![])),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Nim {
@@ -217,6 +227,16 @@ mod tests {
         assert!(play(Nim::new(vec![0, 0])).is_empty());
     }
 
+    #[test]
+    fn terminal_games() {
+        let nim = Nim::terminal_game(GameState::Win(ImpartialPlayer::Previous)).unwrap();
+        assert_eq!(Game::state(&nim), GameState::Win(ImpartialPlayer::Previous));
+
+        assert!(Nim::terminal_game(GameState::Win(ImpartialPlayer::Next)).is_none());
+        assert!(Nim::terminal_game(GameState::Tie).is_none());
+        assert!(Nim::terminal_game(GameState::Playable).is_none());
+    }
+
     #[test]
     fn symmetrical_nim_wins() {
         // a loss in 4 moves: take 1, other player takes from other, take 1, other player takes from other
@@ -12,7 +12,8 @@ use game_solver::{
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display, Formatter},
-    hash::Hash, str::FromStr,
+    hash::Hash,
+    str::FromStr,
 };
 use thiserror::Error;
 
@@ -251,11 +252,7 @@ impl<
     }
 
     fn player(&self) -> PartizanPlayer {
-        if self.move_count % 2 == 0 {
-            PartizanPlayer::Left
-        } else {
-            PartizanPlayer::Right
-        }
+        PartizanPlayer::from_move_count(self.move_count)
     }
 }
 
@@ -347,6 +344,10 @@ impl<
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use game_solver::solve;
+
     use super::*;
 
     fn from_string(string: &str) -> OrderAndChaos<6, 6, 5, 6> {
@@ -471,4 +472,20 @@ mod tests {
 
         assert_eq!(diagonal_board.state(), GameState::Playable);
     }
+
+    #[test]
+    fn solve_finished_line() {
+        // the empty squares are still listed as moves, but the game is over.
+        let horizontal_board = from_string(
+            "......\
+        .XOXXX\
+        .X....\
+        .OOOOO\
+        ......\
+        ......",
+        );
+
+        let score = solve(&horizontal_board, &mut HashMap::new(), None).unwrap();
+        assert_eq!(score > 0, horizontal_board.player() == PartizanPlayer::Left);
+    }
 }
@@ -3,138 +3,209 @@
 #[cfg(feature = "egui")]
 pub mod gui;
 
-use anyhow::Error;
-use array2d::Array2D;
+use anyhow::{anyhow, Error};
 use clap::Args;
 use game_solver::{
     game::{Game, GameState},
     player::{PartizanPlayer, Player},
 };
 use serde::{Deserialize, Serialize};
-use std::fmt::{self, Debug};
-use std::hash::Hash;
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+    str::FromStr,
+};
+use thiserror::Error;
+
+use crate::util::move_failable;
+
+/// The eight directions discs are flipped in.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// A square on the board, as (x, y).
+pub type Square = (usize, usize);
+
+/// Placing a disc, or passing, which is only allowed (and then required)
+/// when there's nowhere to place a disc.
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
+pub enum ReversiMove {
+    Place(Square),
+    Pass,
+}
 
-use crate::util::{move_failable, move_natural::NaturalMove};
+impl Display for ReversiMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Place((x, y)) => write!(f, "{x}-{y}"),
+            Self::Pass => write!(f, "pass"),
+        }
+    }
+}
 
-pub const WIDTH: usize = 6;
-pub const HEIGHT: usize = 6;
+impl FromStr for ReversiMove {
+    type Err = Error;
 
-pub type ReversiMove = NaturalMove<2>;
+    /// Parses a move written as `x-y`, e.g. `1-2`, or `pass`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "pass" {
+            return Ok(Self::Pass);
+        }
 
+        match s.split('-').map(str::parse).collect::<Result<Vec<_>, _>>() {
+            Ok(coordinates) => match coordinates[..] {
+                [x, y] => Ok(Self::Place((x, y))),
+                _ => Err(anyhow!(
+                    "{s} should be two numbers separated by a hyphen (x-y), or pass"
+                )),
+            },
+            Err(_) => Err(anyhow!(
+                "{s} should be two numbers separated by a hyphen (x-y), or pass"
+            )),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum ReversiMoveError {
+    #[error("{}-{} is not on the board.", .0.0, .0.1)]
+    OutOfBounds(Square),
+    #[error("{}-{} is already occupied.", .0.0, .0.1)]
+    Occupied(Square),
+    #[error("a disc on {}-{} wouldn't flip any discs.", .0.0, .0.1)]
+    NoFlips(Square),
+    #[error("a player can only pass when they have nowhere to place a disc.")]
+    CannotPass,
+}
+
+/// A game of Reversi on a board of up to 64 squares,
+/// where the discs of each player are a bitboard (indexed by `y * WIDTH + x`).
 #[derive(Clone, Hash, Eq, PartialEq)]
-pub struct Reversi {
-    /// None if empty, Some(Player) if occupied
-    board: Array2D<Option<PartizanPlayer>>,
+pub struct Reversi<const WIDTH: usize, const HEIGHT: usize> {
+    /// The discs of the player who moves first
+    left: u64,
+    /// The discs of the player who moves second
+    right: u64,
     move_count: usize,
 }
 
-impl Reversi {
-    fn new() -> Self {
-        let mut board = Array2D::filled_with(None, WIDTH, HEIGHT);
-
-        // set middle squares to occupied:
-        board
-            .set(WIDTH / 2 - 1, HEIGHT / 2 - 1, Some(PartizanPlayer::Left))
-            .unwrap();
-        board
-            .set(WIDTH / 2, HEIGHT / 2, Some(PartizanPlayer::Left))
-            .unwrap();
-        board
-            .set(WIDTH / 2 - 1, HEIGHT / 2, Some(PartizanPlayer::Right))
-            .unwrap();
-        board
-            .set(WIDTH / 2, HEIGHT / 2 - 1, Some(PartizanPlayer::Right))
-            .unwrap();
+impl<const WIDTH: usize, const HEIGHT: usize> Default for Reversi<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Reversi<WIDTH, HEIGHT> {
+    /// The usual starting position, with two discs for each player crossed in the middle of the board.
+    ///
+    /// # Panics
+    ///
+    /// If the board has more than 64 squares, or is smaller than 2x2.
+    pub fn new() -> Self {
+        assert!(WIDTH * HEIGHT <= 64, "boards can have at most 64 squares");
+        assert!(WIDTH >= 2 && HEIGHT >= 2, "boards must be at least 2x2");
+
+        let (x, y) = (WIDTH / 2 - 1, HEIGHT / 2 - 1);
 
         Self {
-            board,
+            left: Self::bit((x, y)) | Self::bit((x + 1, y + 1)),
+            right: Self::bit((x, y + 1)) | Self::bit((x + 1, y)),
             move_count: 0,
         }
     }
 
-    fn on_board(&self, x: usize, y: usize) -> bool {
-        x < WIDTH && y < HEIGHT
+    fn bit((x, y): Square) -> u64 {
+        1 << (y * WIDTH + x)
     }
 
-    fn is_valid_move(&self, m: &<Self as Game>::Move) -> Option<Vec<<Self as Game>::Move>> {
-        let cell = *self.board.get(m.0[0], m.0[1]).unwrap();
-
-        if cell.is_some() {
-            return None;
-        }
-
-        let opposing_tile = self.player().next();
-
-        let mut tiles_to_flip = Vec::new();
-
-        let directions: &[(isize, isize)] = &[
-            (0, 1),
-            (1, 1),
-            (1, 0),
-            (1, -1),
-            (0, -1),
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-        ];
+    fn square(index: usize) -> Square {
+        (index % WIDTH, index / WIDTH)
+    }
 
-        for (x_dir, y_dir) in directions {
-            let mut x = m.0[0];
-            let mut y = m.0[1];
+    fn occupied(&self) -> u64 {
+        self.left | self.right
+    }
 
-            x = x.wrapping_add_signed(*x_dir);
-            y = y.wrapping_add_signed(*y_dir);
+    fn discs(&self, player: PartizanPlayer) -> u64 {
+        match player {
+            PartizanPlayer::Left => self.left,
+            PartizanPlayer::Right => self.right,
+        }
+    }
 
-            if self.board.get(x, y) != Some(&Some(opposing_tile)) {
-                continue;
-            }
+    /// The square one step from `square` in `direction`, if it's on the board.
+    fn step((x, y): Square, (dx, dy): (isize, isize)) -> Option<Square> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < WIDTH)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < HEIGHT)?;
+        Some((x, y))
+    }
 
-            x = x.wrapping_add_signed(*x_dir);
-            y = y.wrapping_add_signed(*y_dir);
+    /// The discs that `player` placing a disc on the empty `square` would flip.
+    fn flips(&self, player: PartizanPlayer, square: Square) -> u64 {
+        let own = self.discs(player);
+        let opponent = self.discs(player.next());
 
-            while self.board.get(x, y) == Some(&Some(opposing_tile)) {
-                x = x.wrapping_add_signed(*x_dir);
-                y = y.wrapping_add_signed(*y_dir);
+        DIRECTIONS
+            .into_iter()
+            .map(|direction| {
+                let mut flipped = 0;
+                let mut current = Self::step(square, direction);
 
-                if !self.on_board(x, y) {
-                    break;
+                while let Some(next) = current.filter(|&next| opponent & Self::bit(next) != 0) {
+                    flipped |= Self::bit(next);
+                    current = Self::step(next, direction);
                 }
-            }
 
-            if !self.on_board(x, y) {
-                continue;
-            }
+                // the line of opposing discs has to end with one of the player's own discs
+                match current {
+                    Some(end) if own & Self::bit(end) != 0 => flipped,
+                    _ => 0,
+                }
+            })
+            .fold(0, |flips, flipped| flips | flipped)
+    }
 
-            if self.board.get(x, y) == Some(&Some(self.player())) {
-                loop {
-                    x = x.checked_add_signed(-*x_dir).unwrap();
-                    y = y.checked_add_signed(-*y_dir).unwrap();
+    /// Every empty square `player` can place a disc on.
+    fn placements(&self, player: PartizanPlayer) -> impl Iterator<Item = Square> + '_ {
+        let occupied = self.occupied();
 
-                    if x == m.0[0] && y == m.0[1] {
-                        break;
-                    }
+        (0..WIDTH * HEIGHT)
+            .filter(move |&index| occupied & (1 << index) == 0)
+            .map(Self::square)
+            .filter(move |&square| self.flips(player, square) != 0)
+    }
 
-                    tiles_to_flip.push(NaturalMove([x, y]));
-                }
-            }
-        }
+    fn can_place(&self, player: PartizanPlayer) -> bool {
+        self.placements(player).next().is_some()
+    }
 
-        if tiles_to_flip.is_empty() {
-            None
-        } else {
-            Some(tiles_to_flip)
-        }
+    /// Applies `transform` (which maps squares to squares) to every square of `board`.
+    fn transform_board(board: u64, transform: impl Fn(Square) -> Square) -> u64 {
+        (0..WIDTH * HEIGHT)
+            .filter(|&index| board & (1 << index) != 0)
+            .fold(0, |transformed, index| {
+                transformed | Self::bit(transform(Self::square(index)))
+            })
     }
 }
 
-impl Game for Reversi {
+impl<const WIDTH: usize, const HEIGHT: usize> Game for Reversi<WIDTH, HEIGHT> {
     type Move = ReversiMove;
     type Iter<'a> = std::vec::IntoIter<Self::Move>;
     type Player = PartizanPlayer;
-    type MoveError = array2d::Error;
+    type MoveError = ReversiMoveError;
 
     fn max_moves(&self) -> Option<usize> {
-        Some(WIDTH * HEIGHT)
+        // every empty square is filled at most once, and a pass is always followed by a placement
+        Some(2 * (WIDTH * HEIGHT - 4))
     }
 
     fn move_count(&self) -> usize {
@@ -142,12 +213,36 @@ impl Game for Reversi {
     }
 
     fn make_move(&mut self, m: &Self::Move) -> Result<(), Self::MoveError> {
-        let move_set = self.is_valid_move(m).unwrap();
+        let player = self.player();
+
+        match *m {
+            ReversiMove::Place(square) => {
+                if square.0 >= WIDTH || square.1 >= HEIGHT {
+                    return Err(ReversiMoveError::OutOfBounds(square));
+                }
+
+                if self.occupied() & Self::bit(square) != 0 {
+                    return Err(ReversiMoveError::Occupied(square));
+                }
 
-        self.board.set(m.0[0], m.0[1], Some(self.player())).unwrap();
+                let flips = self.flips(player, square);
+                if flips == 0 {
+                    return Err(ReversiMoveError::NoFlips(square));
+                }
 
-        for idx in move_set {
-            self.board.set(idx.0[0], idx.0[1], Some(self.player()))?;
+                let (own, opponent) = match player {
+                    PartizanPlayer::Left => (&mut self.left, &mut self.right),
+                    PartizanPlayer::Right => (&mut self.right, &mut self.left),
+                };
+                *own |= flips | Self::bit(square);
+                *opponent &= !flips;
+            }
+            ReversiMove::Pass => {
+                // (two passes in a row would end the game, so the opponent has to be able to place a disc)
+                if self.can_place(player) || !self.can_place(player.next()) {
+                    return Err(ReversiMoveError::CannotPass);
+                }
+            }
         }
 
         self.move_count += 1;
@@ -156,36 +251,25 @@ impl Game for Reversi {
     }
 
     fn possible_moves(&self) -> Self::Iter<'_> {
-        let mut moves = Vec::new();
-        for x in 0..WIDTH {
-            for y in 0..HEIGHT {
-                if self.is_valid_move(&NaturalMove([x, y])).is_some() {
-                    moves.push(NaturalMove([x, y]));
-                }
-            }
+        let mut moves = self
+            .placements(self.player())
+            .map(ReversiMove::Place)
+            .collect::<Vec<_>>();
+
+        if moves.is_empty() && self.can_place(self.player().next()) {
+            moves.push(ReversiMove::Pass);
         }
+
         moves.into_iter()
     }
 
     fn state(&self) -> GameState<Self::Player> {
-        if self.possible_moves().len() > 0 {
+        // the game only ends once neither player can place a disc
+        if self.can_place(self.player()) || self.can_place(self.player().next()) {
             return GameState::Playable;
         }
 
-        let mut player_one_count = 0;
-        let mut player_two_count = 0;
-
-        for x in 0..WIDTH {
-            for y in 0..HEIGHT {
-                match *self.board.get(x, y).unwrap() {
-                    Some(PartizanPlayer::Left) => player_one_count += 1,
-                    Some(PartizanPlayer::Right) => player_two_count += 1,
-                    None => (),
-                }
-            }
-        }
-
-        match player_one_count.cmp(&player_two_count) {
+        match self.left.count_ones().cmp(&self.right.count_ones()) {
             std::cmp::Ordering::Greater => GameState::Win(PartizanPlayer::Left),
             std::cmp::Ordering::Less => GameState::Win(PartizanPlayer::Right),
             std::cmp::Ordering::Equal => GameState::Tie,
@@ -193,11 +277,41 @@ impl Game for Reversi {
     }
 
     fn player(&self) -> PartizanPlayer {
-        if self.move_count % 2 == 0 {
-            PartizanPlayer::Left
-        } else {
-            PartizanPlayer::Right
+        PartizanPlayer::from_move_count(self.move_count)
+    }
+
+    fn canonical_form(&self) -> Self {
+        let (width, height) = (WIDTH - 1, HEIGHT - 1);
+        let mut symmetries: Vec<Box<dyn Fn(Square) -> Square>> = vec![
+            Box::new(|(x, y)| (x, y)),
+            Box::new(move |(x, y)| (width - x, y)),
+            Box::new(move |(x, y)| (x, height - y)),
+            Box::new(move |(x, y)| (width - x, height - y)),
+        ];
+
+        // square boards can also be reflected along their diagonals
+        if WIDTH == HEIGHT {
+            symmetries.extend::<[Box<dyn Fn(Square) -> Square>; 4]>([
+                Box::new(|(x, y)| (y, x)),
+                Box::new(move |(x, y)| (width - y, x)),
+                Box::new(move |(x, y)| (y, height - x)),
+                Box::new(move |(x, y)| (width - y, height - x)),
+            ]);
         }
+
+        symmetries
+            .iter()
+            .map(|symmetry| Self {
+                left: Self::transform_board(self.left, symmetry),
+                right: Self::transform_board(self.right, symmetry),
+                move_count: self.move_count,
+            })
+            .min_by_key(|game| (game.left, game.right))
+            .unwrap()
+    }
+
+    fn display_move(&self, m: &Self::Move) -> String {
+        m.to_string()
     }
 }
 
@@ -209,18 +323,23 @@ fn player_to_char(player: Option<PartizanPlayer>) -> char {
     }
 }
 
-impl fmt::Display for Reversi {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl<const WIDTH: usize, const HEIGHT: usize> Display for Reversi<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f, "Current player: {}", player_to_char(Some(self.player())))?;
 
         let moves = self.possible_moves().collect::<Vec<_>>();
 
         for y in 0..HEIGHT {
             for x in 0..WIDTH {
-                let character = if moves.contains(&NaturalMove([x, y])) {
+                let bit = Self::bit((x, y));
+                let character = if moves.contains(&ReversiMove::Place((x, y))) {
                     '*'
+                } else if self.left & bit != 0 {
+                    player_to_char(Some(PartizanPlayer::Left))
+                } else if self.right & bit != 0 {
+                    player_to_char(Some(PartizanPlayer::Right))
                 } else {
-                    player_to_char(*self.board.get(x, y).unwrap())
+                    player_to_char(None)
                 };
 
                 write!(f, "{}", character)?;
@@ -232,9 +351,9 @@ impl fmt::Display for Reversi {
     }
 }
 
-impl Debug for Reversi {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <Self as fmt::Display>::fmt(self, f)
+impl<const WIDTH: usize, const HEIGHT: usize> Debug for Reversi<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        <Self as Display>::fmt(self, f)
     }
 }
 
@@ -243,22 +362,125 @@ impl Debug for Reversi {
 #[doc = include_str!("./README.md")]
 #[derive(Args, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
 pub struct ReversiArgs {
-    /// Reversi moves, ordered as x1-y1 x2-y2 ...
-    #[arg(value_parser = clap::value_parser!(ReversiMove))]
-    moves: Vec<ReversiMove>,
+    /// Reversi moves, ordered as x1-y1 x2-y2 ..., where a player with nowhere to move plays pass
+    moves: Vec<String>,
 }
 
-impl TryFrom<ReversiArgs> for Reversi {
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<ReversiArgs> for Reversi<WIDTH, HEIGHT> {
     type Error = Error;
 
     fn try_from(value: ReversiArgs) -> Result<Self, Self::Error> {
         let mut game = Reversi::new();
 
         // parse every move in args, e.g. 0-0 1-1 in args
-        for game_move in value.moves {
-            move_failable(&mut game, &game_move)?;
+        for arg in value.moves {
+            move_failable(&mut game, &ReversiMove::from_str(&arg)?)?;
         }
 
         Ok(game)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use game_solver::{solve, transposition::SymmetryAwareTable};
+
+    use super::*;
+    use crate::util::reference_score;
+
+    fn play<const WIDTH: usize, const HEIGHT: usize>(
+        game: &mut Reversi<WIDTH, HEIGHT>,
+        moves: &[&str],
+    ) {
+        for m in moves {
+            game.make_move(&m.parse().unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn moves_round_trip() {
+        assert_eq!(
+            "1-2".parse::<ReversiMove>().unwrap(),
+            ReversiMove::Place((1, 2))
+        );
+        assert_eq!("pass".parse::<ReversiMove>().unwrap(), ReversiMove::Pass);
+        assert_eq!(ReversiMove::Place((1, 2)).to_string(), "1-2");
+        assert_eq!(ReversiMove::Pass.to_string(), "pass");
+
+        assert!("1".parse::<ReversiMove>().is_err());
+        assert!("1-a".parse::<ReversiMove>().is_err());
+        assert!("1-2-3".parse::<ReversiMove>().is_err());
+    }
+
+    #[test]
+    fn opening_moves_flip_discs() {
+        let mut game = Reversi::<8, 8>::new();
+        assert_eq!(game.possible_moves().len(), 4);
+
+        play(&mut game, &["3-5"]);
+        assert_eq!(game.left.count_ones(), 4);
+        assert_eq!(game.right.count_ones(), 1);
+        assert_ne!(game.left & Reversi::<8, 8>::bit((3, 4)), 0);
+
+        assert!(matches!(
+            game.make_move(&ReversiMove::Place((3, 5))),
+            Err(ReversiMoveError::Occupied(_))
+        ));
+        assert!(matches!(
+            game.make_move(&ReversiMove::Place((0, 0))),
+            Err(ReversiMoveError::NoFlips(_))
+        ));
+        assert!(matches!(
+            game.make_move(&ReversiMove::Pass),
+            Err(ReversiMoveError::CannotPass)
+        ));
+    }
+
+    #[test]
+    fn players_without_placements_pass() {
+        // the first player has nowhere to place a disc, but the second player does.
+        let game = Reversi::<5, 1> {
+            left: 0b00010,
+            right: 0b00001,
+            move_count: 0,
+        };
+
+        assert_eq!(
+            game.possible_moves().collect::<Vec<_>>(),
+            [ReversiMove::Pass]
+        );
+        assert_eq!(game.state(), GameState::Playable);
+
+        let mut passed = game.clone();
+        play(&mut passed, &["pass", "2-0"]);
+        assert_eq!(passed.right, 0b00111);
+        assert_eq!(passed.state(), GameState::Win(PartizanPlayer::Right));
+    }
+
+    #[test]
+    fn canonical_forms_match_under_symmetry() {
+        let mut game = Reversi::<6, 6>::new();
+        play(&mut game, &["2-4"]);
+
+        // the same move, reflected along the diagonal
+        let mut reflected = Reversi::<6, 6>::new();
+        play(&mut reflected, &["4-2"]);
+
+        assert_ne!(game, reflected);
+        assert_eq!(game.canonical_form(), reflected.canonical_form());
+    }
+
+    #[test]
+    fn matches_reference() {
+        let game = Reversi::<4, 4>::new();
+        let expected = reference_score(&game);
+
+        assert_eq!(solve(&game, &mut HashMap::new(), None).unwrap(), expected);
+        assert_eq!(
+            solve(&game, &mut SymmetryAwareTable::new(HashMap::new()), None).unwrap(),
+            expected
+        );
+    }
+}
@@ -215,7 +215,7 @@ impl Game for Sprouts {
     }
 
     fn state(&self) -> game_solver::game::GameState<Self::Player> {
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 }
 
@@ -6,22 +6,24 @@ use anyhow::{anyhow, Error};
 use clap::Args;
 use game_solver::{
     game::{Game, GameState},
+    notation::GameParser,
     player::{PartizanPlayer, Player},
 };
 use itertools::Itertools;
-use ndarray::{iter::IndexedIter, ArrayD, Dim, Dimension, IntoDimension, IxDyn, IxDynImpl};
+use ndarray::{iter::IndexedIter, ArrayD, Axis, Dim, Dimension, IntoDimension, IxDyn, IxDynImpl};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use std::{
     fmt::{Debug, Display, Formatter},
     hash::Hash,
-    iter::FilterMap, str::FromStr,
+    iter::FilterMap,
+    str::FromStr,
 };
 
 use crate::util::move_failable;
 
-#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord, Debug)]
 pub enum Square {
     X,
     O,
@@ -99,7 +101,7 @@ impl FromStr for TicTacToeMove {
             .split('-')
             .map(|num| num.parse::<usize>().map_err(|_| anyhow!("Not a number!")))
             .collect();
-        
+
         Ok(Self(numbers?.into_dimension()))
     }
 }
@@ -249,7 +251,42 @@ impl Game for TicTacToe {
             })
     }
 
-    fn find_immediately_resolvable_game(&self) -> Result<Option<Self>, Self::MoveError> {
+    fn canonical_form(&self) -> Self {
+        // the symmetries of a hypercube are every permutation of its axes,
+        // combined with every reflection along those axes.
+        // we pick the lexicographically smallest board out of all of them.
+        let mut canonical: Option<ArrayD<Option<Square>>> = None;
+
+        for permutation in (0..self.dim).permutations(self.dim) {
+            for reflections in 0..(1usize << self.dim) {
+                let mut board = self.board.view().permuted_axes(IxDyn(&permutation));
+                for axis in 0..self.dim {
+                    if reflections & (1 << axis) != 0 {
+                        board.invert_axis(Axis(axis));
+                    }
+                }
+
+                if canonical
+                    .as_ref()
+                    .map_or(true, |canonical| board.iter().lt(canonical.iter()))
+                {
+                    canonical = Some(board.as_standard_layout().into_owned());
+                }
+            }
+        }
+
+        Self {
+            dim: self.dim,
+            size: self.size,
+            board: canonical.unwrap(),
+            move_count: self.move_count,
+        }
+    }
+
+    fn find_immediately_resolvable_game_among(
+        &self,
+        moves: &[Self::Move],
+    ) -> Result<Option<Self>, Self::MoveError> {
         // check if the amount of moves is less than (size * 2) - 1
         // if it is, then it's impossible to win
         if self.move_count + 1 < self.size * 2 - 1 {
@@ -258,9 +295,9 @@ impl Game for TicTacToe {
 
         let mut best_non_winning_game: Option<Self> = None;
 
-        for m in &mut self.possible_moves() {
+        for m in moves {
             let mut new_self = self.clone();
-            new_self.make_move(&m)?;
+            new_self.make_move(m)?;
             match new_self.state() {
                 GameState::Playable => continue,
                 GameState::Tie => best_non_winning_game = Some(new_self),
@@ -278,11 +315,36 @@ impl Game for TicTacToe {
     }
 
     fn player(&self) -> Self::Player {
-        if self.move_count % 2 == 0 {
-            PartizanPlayer::Left
-        } else {
-            PartizanPlayer::Right
+        PartizanPlayer::from_move_count(self.move_count)
+    }
+
+    fn terminal_game(state: GameState<Self::Player>) -> Option<Self> {
+        // games on the usual 3x3 board, played out in order.
+        let moves: &[[usize; 2]] = match state {
+            GameState::Playable => return None,
+            GameState::Tie => &[
+                [0, 0],
+                [0, 1],
+                [0, 2],
+                [1, 1],
+                [1, 0],
+                [1, 2],
+                [2, 1],
+                [2, 0],
+                [2, 2],
+            ],
+            GameState::Win(PartizanPlayer::Left) => &[[0, 0], [1, 0], [0, 1], [1, 1], [0, 2]],
+            GameState::Win(PartizanPlayer::Right) => {
+                &[[0, 0], [1, 0], [0, 1], [1, 1], [2, 2], [1, 2]]
+            }
+        };
+
+        let mut game = Self::new(2, 3);
+        for m in moves {
+            game.make_move(&TicTacToeMove(IxDyn(m))).ok()?;
         }
+
+        Some(game)
     }
 }
 
@@ -309,6 +371,72 @@ fn offsets(dim: &Dim<IxDynImpl>, size: usize) -> Vec<Vec<i32>> {
         .collect()
 }
 
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TicTacToeParseError {
+    #[error("a board of {0} squares isn't square")]
+    NonSquareBoard(usize),
+    #[error("{0:?} isn't a square - expected X, O, or .")]
+    InvalidSquare(char),
+    #[error("X can't have played {x} times while O played {o} times")]
+    InvalidTurnOrder { x: usize, o: usize },
+}
+
+/// A two-dimensional board, written one row after another,
+/// where every square is `X`, `O`, or `.` if empty - e.g. `X.O.X..O.`.
+///
+/// Boards of other dimensions are written in the same (row-major) order,
+/// but can't be read back.
+impl GameParser for TicTacToe {
+    type ParseError = TicTacToeParseError;
+
+    fn from_notation(s: &str) -> Result<Self, Self::ParseError> {
+        let squares = s
+            .chars()
+            .map(|c| match c {
+                'X' => Ok(Some(Square::X)),
+                'O' => Ok(Some(Square::O)),
+                '.' => Ok(None),
+                c => Err(TicTacToeParseError::InvalidSquare(c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let size = (1..=squares.len())
+            .find(|size| size * size >= squares.len())
+            .filter(|size| size * size == squares.len())
+            .ok_or(TicTacToeParseError::NonSquareBoard(squares.len()))?;
+
+        let x = squares
+            .iter()
+            .filter(|&&square| square == Some(Square::X))
+            .count();
+        let o = squares
+            .iter()
+            .filter(|&&square| square == Some(Square::O))
+            .count();
+        if x != o && x != o + 1 {
+            return Err(TicTacToeParseError::InvalidTurnOrder { x, o });
+        }
+
+        Ok(Self {
+            dim: 2,
+            size,
+            board: ArrayD::from_shape_vec(IxDyn(&[size, size]), squares).unwrap(),
+            move_count: x + o,
+        })
+    }
+
+    fn to_notation(&self) -> String {
+        self.board
+            .iter()
+            .map(|square| match square {
+                Some(Square::X) => 'X',
+                Some(Square::O) => 'O',
+                None => '.',
+            })
+            .collect()
+    }
+}
+
 impl Display for TicTacToeMove {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self.0.as_array_view().as_slice().unwrap())
@@ -333,8 +461,24 @@ impl Debug for TicTacToe {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use game_solver::{move_scores, GameSolveError};
-    use std::collections::HashMap;
+    use game_solver::{
+        config::SolverConfig,
+        find_forced_win,
+        game::max_score,
+        move_scores,
+        notation::{
+            move_scores_from_notation, parse_and_solve, solve_from_notation, ParseAndSolveError,
+        },
+        pns::proof_number_search,
+        solve, solve_minimax, solve_with_config,
+        transposition::SymmetryAwareTable,
+        util::{find_move_between, find_move_sequence_between, perft, perft_hash},
+        GameSolveError,
+    };
+    use std::{
+        collections::HashMap,
+        time::{Duration, Instant},
+    };
 
     fn move_scores_unwrapped(game: &TicTacToe) -> Vec<(TicTacToeMove, isize)> {
         move_scores(game, &mut HashMap::new(), None)
@@ -369,6 +513,209 @@ mod tests {
             .all(|(_, score)| *score == 0));
     }
 
+    #[test]
+    fn draw_value_replaces_tie_score() {
+        let game = TicTacToe::new(2, 3);
+        assert_eq!(solve(&game, &mut HashMap::new(), None).unwrap(), 0);
+
+        for draw_value in [-2, 3] {
+            let config = SolverConfig::new().with_draw_value(draw_value);
+            assert_eq!(
+                solve_with_config(&game, &mut HashMap::new(), &config, None).unwrap(),
+                draw_value
+            );
+        }
+    }
+
+    #[test]
+    fn draw_values_dont_leak_between_solves() {
+        let game = TicTacToe::new(2, 3);
+        let config = SolverConfig::new().with_draw_value(-2);
+        let mut transposition_table = HashMap::new();
+        solve_with_config(&game, &mut transposition_table, &config, None).unwrap();
+
+        // the position after opening in the center was searched with draws scored
+        // from the first player's perspective, but solving it scores them from the second player's.
+        let (_, board) = game.children().nth(4).unwrap();
+        assert_eq!(
+            solve_with_config(&board, &mut transposition_table, &config, None).unwrap(),
+            solve_with_config(&board, &mut HashMap::new(), &config, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn perft_fingerprints() {
+        const PERFT: [u64; 5] = [9, 72, 504, 3024, 15120];
+        const FINGERPRINTS: [u64; 5] = [
+            0x7cdb_bca1_3ecc_5ab9,
+            0x3568_ada8_3f7f_8b8e,
+            0x9f29_c829_0536_2c46,
+            0x403a_1f68_89ba_54ac,
+            0xd590_8dc8_56e7_d364,
+        ];
+
+        let game = TicTacToe::new(2, 3);
+        for depth in 1..=5 {
+            assert_eq!(perft(&game, depth), PERFT[depth - 1], "depth {depth}");
+            assert_eq!(
+                perft_hash(&game, depth),
+                FINGERPRINTS[depth - 1],
+                "depth {depth}"
+            );
+        }
+    }
+
+    #[test]
+    fn forced_wins() {
+        // X completes the diagonal right away.
+        let game = TicTacToe::from_notation("X.O.X..O.").unwrap();
+        assert_eq!(
+            find_forced_win(&game, 1).unwrap().map(|line| line.len()),
+            Some(1)
+        );
+
+        for notation in [
+            "X.O.X..O.",
+            "X...O....",
+            "XO.......",
+            "X.O......",
+            "XO..X..O.",
+        ] {
+            let game = TicTacToe::from_notation(notation).unwrap();
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+            let line = find_forced_win(&game, 9).unwrap();
+            assert_eq!(line.is_some(), score > 0, "{notation}");
+
+            if let Some(line) = line {
+                let mut board = game.clone();
+                for m in &line {
+                    board.make_move(m).unwrap();
+                }
+                assert_eq!(board.state(), GameState::Win(game.player()), "{notation}");
+            }
+        }
+    }
+
+    #[test]
+    fn moves_between_positions() {
+        let empty = TicTacToe::from_notation(".........").unwrap();
+        let corner = TicTacToe::from_notation("X........").unwrap();
+
+        let m = find_move_between(&empty, &corner).unwrap();
+        let mut board = empty.clone();
+        board.make_move(&m).unwrap();
+        assert_eq!(board, corner);
+        assert!(find_move_between(&corner, &empty).is_none());
+
+        let target = TicTacToe::from_notation("X.O.X..O.").unwrap();
+        let moves = find_move_sequence_between(&empty, &target, 9).unwrap();
+        assert_eq!(moves.len(), 4);
+        let mut board = empty.clone();
+        for m in &moves {
+            board.make_move(m).unwrap();
+        }
+        assert_eq!(board, target);
+
+        assert!(find_move_sequence_between(&empty, &target, 3).is_none());
+        assert_eq!(find_move_sequence_between(&empty, &empty, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn minimax_matches_solve() {
+        for notation in ["X.O.X..O.", "XO.......", "X...O..X.", "........."] {
+            let game = TicTacToe::from_notation(notation).unwrap();
+            assert_eq!(
+                solve_minimax(&game, &mut HashMap::new()).unwrap(),
+                solve(&game, &mut HashMap::new(), None).unwrap(),
+                "{notation}"
+            );
+        }
+    }
+
+    #[test]
+    fn notation_round_trips() {
+        let game = TicTacToe::from_notation("X.O.X..O.").unwrap();
+        assert_eq!(game.move_count(), 4);
+        assert_eq!(game.player(), PartizanPlayer::Left);
+        assert_eq!(game.to_notation(), "X.O.X..O.");
+
+        let mut played = TicTacToe::new(2, 3);
+        for m in [[0, 0], [0, 2], [1, 1], [2, 1]] {
+            played
+                .make_move(&TicTacToeMove(m.to_vec().into_dimension()))
+                .unwrap();
+        }
+        assert_eq!(game, played);
+
+        assert_eq!(
+            TicTacToe::from_notation("X.O.X..O"),
+            Err(TicTacToeParseError::NonSquareBoard(8))
+        );
+        assert_eq!(
+            TicTacToe::from_notation("X.O.X..Z."),
+            Err(TicTacToeParseError::InvalidSquare('Z'))
+        );
+        assert_eq!(
+            TicTacToe::from_notation("OO......."),
+            Err(TicTacToeParseError::InvalidTurnOrder { x: 0, o: 2 })
+        );
+    }
+
+    #[test]
+    fn parse_and_solve_wins() {
+        // X to move completes the diagonal, winning on the very next move.
+        let game = TicTacToe::from_notation("X.O.X..O.").unwrap();
+        assert_eq!(
+            parse_and_solve::<TicTacToe>("X.O.X..O.", &mut HashMap::new(), None).unwrap(),
+            max_score(&game).unwrap() - 1
+        );
+        assert!(matches!(
+            parse_and_solve::<TicTacToe>("XX.......", &mut HashMap::new(), None),
+            Err(ParseAndSolveError::ParseError(
+                TicTacToeParseError::InvalidTurnOrder { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn solve_from_notation_entry_points() {
+        let (game, score) = solve_from_notation::<TicTacToe>("X.O.X..O.").unwrap();
+        assert_eq!(game, TicTacToe::from_notation("X.O.X..O.").unwrap());
+        assert_eq!(score, solve(&game, &mut HashMap::new(), None).unwrap());
+
+        let scores = move_scores_from_notation::<TicTacToe>("X.O.X..O.").unwrap();
+        assert_eq!(scores.len(), 5);
+        assert_eq!(scores.iter().map(|(_, score)| *score).max(), Some(score));
+
+        assert!(matches!(
+            move_scores_from_notation::<TicTacToe>("XX......."),
+            Err(ParseAndSolveError::ParseError(
+                TicTacToeParseError::InvalidTurnOrder { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn proof_number_search_matches_solve() {
+        for notation in [
+            "X.O.X..O.",
+            "X...O....",
+            "XX..O....",
+            "XO..X..O.",
+            "X.O......",
+        ] {
+            let game = TicTacToe::from_notation(notation).unwrap();
+            let score = solve(&game, &mut HashMap::new(), None).unwrap();
+
+            let expected = match score.signum() {
+                1 => GameState::Win(game.player()),
+                -1 => GameState::Win(game.player().next()),
+                _ => GameState::Tie,
+            };
+            assert_eq!(proof_number_search(&game).unwrap(), expected, "{notation}");
+        }
+    }
+
     #[test]
     fn test_win() {
         let mut game = TicTacToe::new(2, 3);
@@ -425,6 +772,67 @@ mod tests {
         assert!(game.state() == GameState::Win(PartizanPlayer::Left));
     }
 
+    #[test]
+    fn test_solve_empty_board() {
+        let game = TicTacToe::new(2, 3);
+
+        let start = Instant::now();
+        assert_eq!(solve(&game, &mut HashMap::new(), None).unwrap(), 0);
+
+        // debug builds are too slow (and noisy) to hold to this
+        if !cfg!(debug_assertions) {
+            assert!(start.elapsed() < Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_corner_moves_equal() {
+        let game = TicTacToe::new(2, 3);
+        let corners =
+            [[0, 0], [0, 2], [2, 0], [2, 2]].map(|corner| corner.to_vec().into_dimension());
+
+        let corner_scores = move_scores_unwrapped(&game)
+            .into_iter()
+            .filter(|(m, _)| corners.contains(&m.0))
+            .map(|(_, score)| score)
+            .collect::<Vec<_>>();
+
+        assert_eq!(corner_scores.len(), 4);
+        assert!(corner_scores.iter().all_equal());
+    }
+
+    #[test]
+    fn test_symmetric_positions() {
+        let mut corner = TicTacToe::new(2, 3);
+        corner
+            .make_move(&TicTacToeMove(vec![0, 0].into_dimension()))
+            .unwrap();
+        corner
+            .make_move(&TicTacToeMove(vec![0, 1].into_dimension()))
+            .unwrap();
+
+        // the same position, reflected along the diagonal and rotated
+        let mut reflected = TicTacToe::new(2, 3);
+        reflected
+            .make_move(&TicTacToeMove(vec![2, 2].into_dimension()))
+            .unwrap();
+        reflected
+            .make_move(&TicTacToeMove(vec![1, 2].into_dimension()))
+            .unwrap();
+
+        assert!(corner.canonical_form() == reflected.canonical_form());
+
+        let mut table = SymmetryAwareTable::new(HashMap::new());
+        assert_eq!(
+            solve(&corner, &mut table, None).unwrap(),
+            solve(&reflected, &mut table, None).unwrap()
+        );
+        assert_eq!(
+            solve(&corner, &mut table, None).unwrap(),
+            solve(&corner, &mut HashMap::new(), None).unwrap()
+        );
+    }
+
     #[test]
     fn test_always_tie_1d() {
         let game = TicTacToe::new(1, 3);
@@ -433,4 +841,19 @@ mod tests {
             .iter()
             .all(|(_, score)| *score == 0));
     }
+
+    #[test]
+    fn terminal_games() {
+        for state in [
+            GameState::Tie,
+            GameState::Win(PartizanPlayer::Left),
+            GameState::Win(PartizanPlayer::Right),
+        ] {
+            let game = TicTacToe::terminal_game(state).unwrap();
+            assert_eq!(game.state(), state);
+            assert_eq!(game.possible_moves().count(), 9 - game.move_count());
+        }
+
+        assert!(TicTacToe::terminal_game(GameState::Playable).is_none());
+    }
 }
@@ -1,7 +1,7 @@
 use std::fmt::{Debug, Display};
 
-use game_solver::game::{Game, GameState};
 use anyhow::{anyhow, Result};
+use game_solver::game::{Game, GameState};
 
 #[cfg(feature = "egui")]
 pub mod gui;
@@ -27,3 +27,44 @@ where
     game.make_move(m)
         .map_err(|err| anyhow!("Failed to move: {}", err))
 }
+
+/// A plain negamax search, memoized but without any pruning,
+/// to verify the solver's scores against.
+#[cfg(test)]
+pub fn reference_score<T: Game + Eq + std::hash::Hash>(game: &T) -> isize {
+    fn search<T: Game + Eq + std::hash::Hash>(
+        game: &T,
+        scores: &mut std::collections::HashMap<T, isize>,
+    ) -> isize {
+        if let Some(&score) = scores.get(game) {
+            return score;
+        }
+
+        let score = match game.state() {
+            GameState::Tie => 0,
+            GameState::Win(player) => {
+                let score =
+                    game_solver::game::upper_bound(game).unwrap() - game.move_count() as isize + 1;
+                if player == game.player() {
+                    score
+                } else {
+                    -score
+                }
+            }
+            GameState::Playable => game
+                .possible_moves()
+                .map(|m| {
+                    let mut board = game.clone();
+                    board.make_move(&m).ok().unwrap();
+                    -search(&board, scores)
+                })
+                .max()
+                .unwrap(),
+        };
+
+        scores.insert(game.clone(), score);
+        score
+    }
+
+    search(game, &mut std::collections::HashMap::new())
+}
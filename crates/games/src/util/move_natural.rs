@@ -5,7 +5,7 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_big_array::BigArray;
 
-#[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct NaturalMove<const LENGTH: usize>(#[serde(with = "BigArray")] pub [usize; LENGTH]);
 
 impl<const LENGTH: usize> FromStr for NaturalMove<LENGTH> {
@@ -183,7 +183,7 @@ impl Direction {
             return Err(anyhow::anyhow!("out of width bounds ({WIDTH} <= {new_x})"));
         }
 
-        return Ok(ZenerPosition::Position(new_x.try_into().unwrap(), new_y.try_into().unwrap()));
+        Ok(ZenerPosition::Position(new_x, new_y.try_into().unwrap()))
     }
 }
 
@@ -329,8 +329,7 @@ impl Game for Zener {
             let Some(cell) = self
                 .board
                 .get(x, y)
-                .map(|cell| cell.len().checked_sub(1).map(|i| cell.get(i)).flatten())
-                .flatten()
+                .and_then(|cell| cell.len().checked_sub(1).and_then(|i| cell.get(i)))
             else {
                 continue;
             };
@@ -357,15 +356,11 @@ impl Game for Zener {
             }
         }
 
-        return moves.into_iter();
+        moves.into_iter()
     }
 
     fn player(&self) -> Self::Player {
-        if self.move_count % 2 == 0 {
-            PartizanPlayer::Left
-        } else {
-            PartizanPlayer::Right
-        }
+        PartizanPlayer::from_move_count(self.move_count)
     }
 
     fn state(&self) -> GameState<Self::Player> {
@@ -373,22 +368,16 @@ impl Game for Zener {
             return GameState::Tie;
         }
 
-        <Self as Normal>::state(&self)
+        <Self as Normal>::state(self)
     }
 }
 
 /// Analyzes Zener.
 ///
 #[doc = include_str!("./README.md")]
-#[derive(Args, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Args, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
 pub struct ZenerArgs {}
 
-impl Default for ZenerArgs {
-    fn default() -> Self {
-        Self {}
-    }
-}
-
 impl FromStr for ZenerMove {
     type Err = anyhow::Error;
 
@@ -429,7 +418,7 @@ impl FromStr for ZenerMove {
 impl TryFrom<ZenerArgs> for Zener {
     type Error = anyhow::Error;
 
-    fn try_from(value: ZenerArgs) -> Result<Self, Self::Error> {
+    fn try_from(_value: ZenerArgs) -> Result<Self, Self::Error> {
         Ok(Zener::default())
     }
 }
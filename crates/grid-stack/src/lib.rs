@@ -13,7 +13,9 @@ pub struct Grid<T, const W: usize, const H: usize, const SIZE: usize> {
     pub data: [T; SIZE],
 }
 
-impl<T: Clone + Copy + Default, const W: usize, const H: usize, const SIZE: usize> Default for Grid<T, W, H, SIZE> {
+impl<T: Clone + Copy + Default, const W: usize, const H: usize, const SIZE: usize> Default
+    for Grid<T, W, H, SIZE>
+{
     fn default() -> Self {
         assert!(SIZE == W * H, "SIZE must be equal to W * H");
         Self {
@@ -66,8 +68,12 @@ impl<T, const W: usize, const H: usize, const SIZE: usize> Grid<T, W, H, SIZE> {
 
     // iterators from array2d: https://github.com/HarrisonMc555/array2d
 
-    pub fn row_iter(&self, row_index: usize) -> Result<impl DoubleEndedIterator<Item = &T> + Clone, Error> {
-        let start = self.idx(0, row_index)
+    pub fn row_iter(
+        &self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &T> + Clone, Error> {
+        let start = self
+            .idx(0, row_index)
             .ok_or(Error::IndicesOutOfBounds(row_index, 0))?;
         let end = start + W;
         Ok(self.data[start..end].iter())
@@ -110,7 +116,6 @@ impl<T, const W: usize, const H: usize, const SIZE: usize> Grid<T, W, H, SIZE> {
     }
 }
 
-
 fn indices_row_major(
     width: usize,
     height: usize,
@@ -125,7 +130,9 @@ fn indices_column_major(
     (0..width).flat_map(move |column| (0..height).map(move |row| (column, row)))
 }
 
-impl<T, const W: usize, const H: usize, const SIZE: usize> Index<(usize, usize)> for Grid<T, W, H, SIZE> {
+impl<T, const W: usize, const H: usize, const SIZE: usize> Index<(usize, usize)>
+    for Grid<T, W, H, SIZE>
+{
     type Output = T;
 
     fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
@@ -133,7 +140,9 @@ impl<T, const W: usize, const H: usize, const SIZE: usize> Index<(usize, usize)>
     }
 }
 
-impl<T, const W: usize, const H: usize, const SIZE: usize> IndexMut<(usize, usize)> for Grid<T, W, H, SIZE> {
+impl<T, const W: usize, const H: usize, const SIZE: usize> IndexMut<(usize, usize)>
+    for Grid<T, W, H, SIZE>
+{
     fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
         &mut self.data[Self::direct_idx(x, y)]
     }
@@ -8,7 +8,7 @@ pub enum Outcome {
     Left,
     Right,
     Next,
-    Previous
+    Previous,
 }
 
 /// General game utilities that should be
@@ -20,7 +20,7 @@ pub trait Game {
     fn birthday(&self) -> BigInt;
 
     /// This operation is communative and associative.
-    fn disjinctive_sum(&self, g: Box<dyn Game>) -> Box<dyn Game>;
+    fn disjinctive_sum(&self, _g: Box<dyn Game>) -> Box<dyn Game>;
 
     /// Negates a game, returning its negative variant.
     fn negate(&self) -> Box<dyn Game>;
@@ -30,36 +30,36 @@ pub trait Game {
 
     /// Gets the ordering of a game to another game
     /// Games define a partial ordering, and not a total ordering.
-    fn partial_cmp(&self, other: &Box<dyn Game>) -> Option<Ordering>;
+    fn partial_cmp(&self, other: &dyn Game) -> Option<Ordering>;
 
     /// Checks if two games are equal.
-    fn eq(&self, other: &Box<dyn Game>) -> bool {
+    fn eq(&self, other: &dyn Game) -> bool {
         self.partial_cmp(other) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq for Box<dyn Game> {
     fn eq(&self, other: &Self) -> bool {
-        Game::eq(self.as_ref(), &Box::new(other))
+        Game::eq(self.as_ref(), other.as_ref())
     }
 }
 
 impl PartialOrd for Box<dyn Game> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Game::partial_cmp(self.as_ref(), &Box::new(other))
+        Game::partial_cmp(self.as_ref(), other.as_ref())
     }
 }
 
 /// We define a game by its left and right games,
 /// representing G = {left|right} and all of its options.
-/// 
+///
 /// This is not guaranteed to be the canonical game variant.
-/// 
+///
 /// This is stored as a vector of both the left and right game options: while this
 /// works for small games, this fails for big games. Other games that implement `Game` should be preferred.
 pub struct VecGame {
     left: Vec<Box<dyn Game>>,
-    right: Vec<Box<dyn Game>>
+    right: Vec<Box<dyn Game>>,
 }
 
 impl VecGame {
@@ -75,10 +75,7 @@ impl VecGame {
 
     /// Returns a game where the left and right options are flipped
     pub fn flip(self) -> Self {
-        VecGame::new(
-            self.right,
-            self.left
-        )
+        VecGame::new(self.right, self.left)
     }
 
     /// Returns the infinitesimal star game: {0|0} = *
@@ -97,23 +94,20 @@ impl VecGame {
     }
 
     pub const fn new(left: Vec<Box<dyn Game>>, right: Vec<Box<dyn Game>>) -> Self {
-        Self {
-            left,
-            right
-        }
+        Self { left, right }
     }
 }
 
 impl Game for VecGame {
     fn birthday(&self) -> BigInt {
         if self.left.is_empty() && self.right.is_empty() {
-            return BigInt::ZERO
+            return BigInt::ZERO;
         }
 
         unimplemented!()
     }
 
-    fn disjinctive_sum(&self, g: Box<dyn Game>) -> Box<dyn Game> {
+    fn disjinctive_sum(&self, _g: Box<dyn Game>) -> Box<dyn Game> {
         unimplemented!()
     }
 
@@ -124,11 +118,11 @@ impl Game for VecGame {
     fn negate(&self) -> Box<dyn Game> {
         Box::new(VecGame::new(
             self.left.iter().map(|g| g.negate()).collect(),
-            self.right.iter().map(|g| g.negate()).collect()
+            self.right.iter().map(|g| g.negate()).collect(),
         ))
     }
 
-    fn partial_cmp(&self, other: &Box<dyn Game>) -> Option<Ordering> {
+    fn partial_cmp(&self, _other: &dyn Game) -> Option<Ordering> {
         unimplemented!()
     }
 }
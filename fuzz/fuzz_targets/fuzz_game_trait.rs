@@ -0,0 +1,65 @@
+#![no_main]
+
+use std::fmt::Debug;
+
+use arbitrary::Arbitrary;
+use game_solver::game::{Game, GameState};
+use games::{
+    naive_nim::Nim,
+    tic_tac_toe::{TicTacToe, TicTacToeArgs},
+};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Input {
+    Nim { heaps: Vec<u8>, moves: Vec<u16> },
+    TicTacToe { moves: Vec<u16> },
+}
+
+/// Checks the invariants every [`Game`] implementation should uphold in this position,
+/// then plays one of its possible moves.
+///
+/// Returns false once the game is over.
+fn validate_game<T: Game + Debug>(game: &mut T, choice: u16) -> bool {
+    let moves = game.possible_moves().collect::<Vec<_>>();
+    let state = game.state();
+
+    if let Some(max_moves) = game.max_moves() {
+        assert!(game.move_count() <= max_moves, "move_count exceeded max_moves in {game:?}");
+    }
+
+    if state != GameState::Playable {
+        return false;
+    }
+
+    assert!(!moves.is_empty(), "{game:?} is playable but has no moves");
+
+    for m in &moves {
+        let mut next = game.clone();
+        assert!(next.make_move(m).is_ok(), "a possible move was rejected in {game:?}");
+        assert_eq!(next.move_count(), game.move_count() + 1);
+    }
+
+    game.make_move(&moves[choice as usize % moves.len()]).unwrap();
+    true
+}
+
+fn play<T: Game + Debug>(mut game: T, moves: Vec<u16>) {
+    for choice in moves {
+        if !validate_game(&mut game, choice) {
+            break;
+        }
+    }
+}
+
+fuzz_target!(|input: Input| {
+    match input {
+        Input::Nim { heaps, moves } => {
+            let heaps = heaps.iter().take(8).map(|&heap| heap as usize).collect();
+            play(Nim::new(heaps), moves);
+        }
+        Input::TicTacToe { moves } => {
+            play(TicTacToe::try_from(TicTacToeArgs::default()).unwrap(), moves);
+        }
+    }
+});
@@ -0,0 +1,40 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use arbitrary::Arbitrary;
+use game_solver::{
+    game::{score_bounds, Game},
+    solve,
+};
+use games::naive_nim::Nim;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    heaps: Vec<u8>,
+    /// Every choice picks one of the possible moves, wrapping around.
+    moves: Vec<u16>,
+}
+
+fuzz_target!(|input: Input| {
+    // keep positions small enough to solve quickly
+    let heaps = input.heaps.iter().take(4).map(|&heap| (heap % 6) as usize).collect();
+    let mut game = Nim::new(heaps);
+
+    for choice in input.moves {
+        let moves = game.possible_moves().collect::<Vec<_>>();
+        if moves.is_empty() {
+            break;
+        }
+
+        game.make_move(&moves[choice as usize % moves.len()]).unwrap();
+    }
+
+    let score = solve(&game, &mut HashMap::new(), None).unwrap();
+    let bounds = score_bounds(&game).unwrap();
+    assert!(
+        bounds.contains(&score),
+        "score {score} is out of bounds {bounds:?} for {game:?}",
+    );
+});